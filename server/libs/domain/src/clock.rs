@@ -0,0 +1,51 @@
+//! Clock Module
+//!
+//! Provides the source of the current time used by validation, so that it
+//! can be pinned in tests.
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time.
+pub trait Clock: Send + Sync {
+    /// Returns the current time (UTC).
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock reading the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always returns the same instant.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use domain::clock::{Clock, FixedClock};
+///
+/// let at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// assert_eq!(FixedClock::new(at).now(), at);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    at: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// Creates a new FixedClock stopped at the given instant.
+    pub fn new(at: DateTime<Utc>) -> Self {
+        Self { at }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.at
+    }
+}
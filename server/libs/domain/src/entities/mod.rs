@@ -1,3 +1,3 @@
 mod sensor_data;
 
-pub use sensor_data::{SensorData, SensorMeasurement};
+pub use sensor_data::{OriginalMeasurement, SensorData, SensorMeasurement};
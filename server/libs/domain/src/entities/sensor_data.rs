@@ -8,6 +8,13 @@ pub struct SensorData {
     pub temperature: Option<SensorMeasurement>,
     pub humidity: Option<SensorMeasurement>,
     pub co2: Option<SensorMeasurement>,
+    pub pressure: Option<SensorMeasurement>,
+    pub pm1_0: Option<SensorMeasurement>,
+    pub pm2_5: Option<SensorMeasurement>,
+    pub pm10: Option<SensorMeasurement>,
+    pub voc: Option<SensorMeasurement>,
+    pub illuminance: Option<SensorMeasurement>,
+    pub sound_level: Option<SensorMeasurement>,
     pub additional_sensors: HashMap<String, SensorMeasurement>,
 }
 
@@ -15,6 +22,26 @@ pub struct SensorData {
 pub struct SensorMeasurement {
     pub value: f64,
     pub unit: String,
+    /// The value and unit as received, kept for audit when the measurement
+    /// was converted to its canonical unit.
+    pub original: Option<OriginalMeasurement>,
+}
+
+/// A measurement exactly as the device reported it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OriginalMeasurement {
+    pub value: f64,
+    pub unit: String,
+}
+
+impl SensorMeasurement {
+    pub fn new(value: f64, unit: impl Into<String>) -> Self {
+        Self {
+            value,
+            unit: unit.into(),
+            original: None,
+        }
+    }
 }
 
 impl SensorData {
@@ -25,31 +52,64 @@ impl SensorData {
             temperature: None,
             humidity: None,
             co2: None,
+            pressure: None,
+            pm1_0: None,
+            pm2_5: None,
+            pm10: None,
+            voc: None,
+            illuminance: None,
+            sound_level: None,
             additional_sensors: HashMap::new(),
         }
     }
 
     pub fn with_temperature(mut self, value: f64, unit: impl Into<String>) -> Self {
-        self.temperature = Some(SensorMeasurement {
-            value,
-            unit: unit.into(),
-        });
+        self.temperature = Some(SensorMeasurement::new(value, unit));
         self
     }
 
     pub fn with_humidity(mut self, value: f64, unit: impl Into<String>) -> Self {
-        self.humidity = Some(SensorMeasurement {
-            value,
-            unit: unit.into(),
-        });
+        self.humidity = Some(SensorMeasurement::new(value, unit));
         self
     }
 
     pub fn with_co2(mut self, value: f64, unit: impl Into<String>) -> Self {
-        self.co2 = Some(SensorMeasurement {
-            value,
-            unit: unit.into(),
-        });
+        self.co2 = Some(SensorMeasurement::new(value, unit));
+        self
+    }
+
+    pub fn with_pressure(mut self, value: f64, unit: impl Into<String>) -> Self {
+        self.pressure = Some(SensorMeasurement::new(value, unit));
+        self
+    }
+
+    pub fn with_pm1_0(mut self, value: f64, unit: impl Into<String>) -> Self {
+        self.pm1_0 = Some(SensorMeasurement::new(value, unit));
+        self
+    }
+
+    pub fn with_pm2_5(mut self, value: f64, unit: impl Into<String>) -> Self {
+        self.pm2_5 = Some(SensorMeasurement::new(value, unit));
+        self
+    }
+
+    pub fn with_pm10(mut self, value: f64, unit: impl Into<String>) -> Self {
+        self.pm10 = Some(SensorMeasurement::new(value, unit));
+        self
+    }
+
+    pub fn with_voc(mut self, value: f64, unit: impl Into<String>) -> Self {
+        self.voc = Some(SensorMeasurement::new(value, unit));
+        self
+    }
+
+    pub fn with_illuminance(mut self, value: f64, unit: impl Into<String>) -> Self {
+        self.illuminance = Some(SensorMeasurement::new(value, unit));
+        self
+    }
+
+    pub fn with_sound_level(mut self, value: f64, unit: impl Into<String>) -> Self {
+        self.sound_level = Some(SensorMeasurement::new(value, unit));
         self
     }
 
@@ -59,13 +119,8 @@ impl SensorData {
         value: f64,
         unit: impl Into<String>,
     ) -> Self {
-        self.additional_sensors.insert(
-            name.into(),
-            SensorMeasurement {
-                value,
-                unit: unit.into(),
-            },
-        );
+        self.additional_sensors
+            .insert(name.into(), SensorMeasurement::new(value, unit));
         self
     }
 }
@@ -1,3 +1,4 @@
+pub mod clock;
 pub mod entities;
 pub mod repositories;
 pub mod sensors;
@@ -1,3 +1,7 @@
+mod page;
 mod sensor_repository;
 
-pub use sensor_repository::SensorRepository;
+pub use page::{InvalidPageToken, Page, PageRequest, PageToken};
+pub use sensor_repository::{
+    QueryOptions, SaveOutcome, SensorDataStream, SensorRepository, SortOrder, TIMESTAMP_PRECISION,
+};
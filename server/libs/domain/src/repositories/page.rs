@@ -0,0 +1,140 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use crate::repositories::SortOrder;
+
+/// Position of the last reading of a page, used to resume the query.
+///
+/// The token combines the reading's timestamp with the storage-specific
+/// id of the record, so readings sharing a timestamp are neither skipped
+/// nor repeated. Clients only ever see its string form and must treat it
+/// as opaque.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::repositories::PageToken;
+///
+/// let token = PageToken::new(Utc::now(), "65f1c0de");
+/// let parsed: PageToken = token.to_string().parse().unwrap();
+///
+/// assert_eq!(parsed, token);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageToken {
+    pub timestamp: DateTime<Utc>,
+    pub id: String,
+}
+
+impl PageToken {
+    pub fn new(timestamp: DateTime<Utc>, id: impl Into<String>) -> Self {
+        Self {
+            timestamp,
+            id: id.into(),
+        }
+    }
+}
+
+impl fmt::Display for PageToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.timestamp.timestamp_nanos_opt().unwrap_or_default();
+        write!(f, "{}_{}", nanos, self.id)
+    }
+}
+
+impl FromStr for PageToken {
+    type Err = InvalidPageToken;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (nanos, id) = s.split_once('_').ok_or(InvalidPageToken)?;
+        let nanos: i64 = nanos.parse().map_err(|_| InvalidPageToken)?;
+        if id.is_empty() {
+            return Err(InvalidPageToken);
+        }
+        Ok(Self::new(DateTime::from_timestamp_nanos(nanos), id))
+    }
+}
+
+/// Error returned when a page token string cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPageToken;
+
+impl fmt::Display for InvalidPageToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid page token")
+    }
+}
+
+impl std::error::Error for InvalidPageToken {}
+
+/// Parameters of a paginated query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    /// Maximum number of readings in the page.
+    pub limit: u32,
+    /// Order of the readings, by timestamp.
+    pub order: SortOrder,
+    /// Token of the previous page, or `None` for the first page.
+    pub after: Option<PageToken>,
+}
+
+impl PageRequest {
+    pub fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            order: SortOrder::default(),
+            after: None,
+        }
+    }
+
+    pub fn with_order(mut self, order: SortOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn with_after(mut self, after: PageToken) -> Self {
+        self.after = Some(after);
+        self
+    }
+}
+
+/// A page of results plus the token to fetch the next one.
+///
+/// `next` is `None` once the last page has been reached.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next: Option<PageToken>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_string() {
+        let token = PageToken::new(Utc::now(), "65f1c0de0000000000000000");
+
+        let parsed: PageToken = token.to_string().parse().unwrap();
+
+        assert_eq!(parsed, token);
+    }
+
+    #[test]
+    fn fails_without_separator() {
+        assert_eq!("12345".parse::<PageToken>(), Err(InvalidPageToken));
+    }
+
+    #[test]
+    fn fails_with_non_numeric_timestamp() {
+        assert_eq!("abc_65f1".parse::<PageToken>(), Err(InvalidPageToken));
+    }
+
+    #[test]
+    fn fails_with_empty_id() {
+        assert_eq!("12345_".parse::<PageToken>(), Err(InvalidPageToken));
+    }
+}
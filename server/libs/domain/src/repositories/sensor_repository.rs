@@ -1,10 +1,116 @@
 use crate::entities::SensorData;
+use crate::repositories::{Page, PageRequest};
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+
+/// Stream of readings produced lazily from the underlying storage.
+pub type SensorDataStream = BoxStream<'static, Result<SensorData>>;
+
+/// Order in which readings are returned, by timestamp.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Number of fractional-second digits timestamps are stored with.
+///
+/// Every backend truncates timestamps to milliseconds, the finest precision
+/// BSON dates keep, so readings are stored and deduplicated the same way
+/// whatever the storage.
+pub const TIMESTAMP_PRECISION: u16 = 3;
+
+/// Result of saving a single reading.
+///
+/// Readings are identified by their `device_id` and `timestamp`, truncated to
+/// [`TIMESTAMP_PRECISION`]; saving the same pair twice stores it only once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    /// The reading was not stored before and has been saved.
+    Inserted,
+    /// A reading with the same device and timestamp already exists; nothing was written.
+    Duplicate,
+}
+
+/// Options for queries returning several readings.
+///
+/// # Examples
+///
+/// ```
+/// use domain::repositories::{QueryOptions, SortOrder};
+///
+/// let options = QueryOptions::default()
+///     .with_limit(100)
+///     .with_order(SortOrder::Descending);
+///
+/// assert_eq!(options.limit, Some(100));
+/// assert_eq!(options.order, SortOrder::Descending);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueryOptions {
+    /// Maximum number of readings to return. `None` means unbounded.
+    pub limit: Option<u32>,
+    /// Order of the returned readings.
+    pub order: SortOrder,
+}
+
+impl QueryOptions {
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_order(mut self, order: SortOrder) -> Self {
+        self.order = order;
+        self
+    }
+}
 
 #[async_trait]
 pub trait SensorRepository: Send + Sync {
-    async fn save(&self, data: &SensorData) -> Result<()>;
+    /// Saves a reading unless one with the same device and timestamp exists.
+    ///
+    /// The timestamp is truncated to [`TIMESTAMP_PRECISION`] before it is
+    /// stored and compared, so two readings of a device less than a
+    /// millisecond apart are duplicates on every backend.
+    async fn save(&self, data: &SensorData) -> Result<SaveOutcome>;
+
+    /// Saves several readings in one round trip.
+    ///
+    /// Returns the outcome of every reading, in input order.
+    async fn save_many(&self, data: &[SensorData]) -> Result<Vec<SaveOutcome>>;
 
     async fn find_by_device_id(&self, device_id: &str) -> Result<Vec<SensorData>>;
+
+    /// Returns the readings of a device whose timestamp lies in `[from, to)`.
+    async fn find_by_device_in_range(
+        &self,
+        device_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        options: QueryOptions,
+    ) -> Result<Vec<SensorData>>;
+
+    /// Returns one page of a device's readings, ordered by timestamp.
+    ///
+    /// Pass the returned `Page::next` back in `PageRequest::after` to fetch
+    /// the following page.
+    async fn find_page_by_device_id(
+        &self,
+        device_id: &str,
+        request: PageRequest,
+    ) -> Result<Page<SensorData>>;
+
+    /// Streams every reading of a device in ascending timestamp order
+    /// without loading them all into memory.
+    async fn stream_by_device_id(&self, device_id: &str) -> Result<SensorDataStream>;
+
+    /// Returns the most recent reading of a device, if it has any.
+    async fn latest_for_device(&self, device_id: &str) -> Result<Option<SensorData>>;
+
+    /// Returns the most recent reading of every device, ordered by device id.
+    async fn latest_for_all_devices(&self) -> Result<Vec<SensorData>>;
 }
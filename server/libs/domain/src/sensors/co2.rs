@@ -4,12 +4,9 @@
 
 use chrono::{DateTime, Utc};
 
+use crate::clock::{Clock, SystemClock};
 use crate::sensors::error::SensorValidationError;
-
-/// Minimum allowed value for CO2 sensor (ppm)
-const MIN_VALUE: f64 = 0.0;
-/// Maximum allowed value for CO2 sensor (ppm)
-const MAX_VALUE: f64 = 50_000.0;
+use crate::sensors::policy::{ValidationPolicy, ValueRange};
 
 /// Enumeration representing the unit of CO2 measurement.
 ///
@@ -22,6 +19,9 @@ pub enum CO2Unit {
 }
 
 impl CO2Unit {
+    /// The unit measurements are stored in.
+    pub const CANONICAL: CO2Unit = CO2Unit::Ppm;
+
     /// Returns the unit as a string slice.
     ///
     /// # Returns
@@ -72,7 +72,8 @@ impl TryFrom<&str> for CO2Unit {
 ///
 /// * `device_id` - Unique identifier for the device
 /// * `timestamp` - Measurement time (UTC)
-/// * `value` - CO2 concentration (0.0 to 50,000.0 ppm)
+/// * `value` - CO2 concentration (0.0 to 50,000.0 ppm unless the validation
+///   policy sets another range)
 /// * `unit` - Unit of measurement
 ///
 /// # Examples
@@ -100,13 +101,17 @@ pub struct CO2Sensor {
 }
 
 impl CO2Sensor {
-    /// Creates a new CO2Sensor instance.
+    /// Default range of allowed values (0.0 to 50,000.0 ppm)
+    pub const DEFAULT_RANGE: ValueRange = ValueRange::new(0.0, 50_000.0);
+
+    /// Creates a new CO2Sensor instance validated against the default range.
     ///
     /// # Arguments
     ///
     /// * `device_id` - Unique identifier for the device (must not be empty)
     /// * `timestamp` - Measurement time (must not be in the future)
-    /// * `value` - CO2 concentration (0.0 to 50,000.0 ppm)
+    /// * `value` - CO2 concentration (0.0 to 50,000.0 ppm unless the validation
+    ///   policy sets another range)
     /// * `unit` - Unit of measurement
     ///
     /// # Returns
@@ -123,22 +128,44 @@ impl CO2Sensor {
         timestamp: DateTime<Utc>,
         value: f64,
         unit: CO2Unit,
+    ) -> Result<Self, SensorValidationError> {
+        Self::with_policy(
+            device_id,
+            timestamp,
+            value,
+            unit,
+            &ValidationPolicy::default(),
+            &SystemClock,
+        )
+    }
+
+    /// Creates a new CO2Sensor instance validated against the range the policy
+    /// sets for the device.
+    ///
+    /// Timestamps are checked against `clock` and the timestamp rules of the
+    /// policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as `new`, and:
+    ///
+    /// * `SensorValidationError::StaleTimestamp` - If the policy sets a maximum
+    ///   age and the timestamp is older
+    pub fn with_policy(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: CO2Unit,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
     ) -> Result<Self, SensorValidationError> {
         if device_id.is_empty() {
             return Err(SensorValidationError::EmptyDeviceId);
         }
 
-        if timestamp > Utc::now() {
-            return Err(SensorValidationError::FutureTimestamp);
-        }
+        policy.timestamps.check(timestamp, clock.now())?;
 
-        if !(MIN_VALUE..=MAX_VALUE).contains(&value) {
-            return Err(SensorValidationError::ValueOutOfRange {
-                value,
-                min: MIN_VALUE,
-                max: MAX_VALUE,
-            });
-        }
+        Self::validate_value(value, policy.limits_for(&device_id).co2)?;
 
         Ok(Self {
             device_id,
@@ -148,6 +175,23 @@ impl CO2Sensor {
         })
     }
 
+    /// Checks that a value lies within the given range.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn validate_value(value: f64, range: ValueRange) -> Result<(), SensorValidationError> {
+        if !range.contains(value) {
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: range.min,
+                max: range.max,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Returns the device ID.
     pub fn device_id(&self) -> &str {
         &self.device_id
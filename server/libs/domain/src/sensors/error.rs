@@ -4,8 +4,10 @@ use std::fmt;
 pub enum SensorValidationError {
     EmptyDeviceId,
     FutureTimestamp,
+    StaleTimestamp { max_age_days: u32 },
     ValueOutOfRange { value: f64, min: f64, max: f64 },
     InvalidUnit(String),
+    UnknownKind(String),
 }
 
 impl fmt::Display for SensorValidationError {
@@ -17,12 +19,18 @@ impl fmt::Display for SensorValidationError {
             SensorValidationError::FutureTimestamp => {
                 write!(f, "timestamp must not be in the future")
             }
+            SensorValidationError::StaleTimestamp { max_age_days } => {
+                write!(f, "timestamp must not be older than {} days", max_age_days)
+            }
             SensorValidationError::ValueOutOfRange { value, min, max } => {
                 write!(f, "value {} is out of range [{}, {}]", value, min, max)
             }
             SensorValidationError::InvalidUnit(unit) => {
                 write!(f, "invalid unit: {}", unit)
             }
+            SensorValidationError::UnknownKind(kind) => {
+                write!(f, "unknown sensor kind: {}", kind)
+            }
         }
     }
 }
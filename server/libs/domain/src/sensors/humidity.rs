@@ -4,12 +4,9 @@
 
 use chrono::{DateTime, Utc};
 
+use crate::clock::{Clock, SystemClock};
 use crate::sensors::error::SensorValidationError;
-
-/// Minimum allowed value for humidity sensor (%)
-const MIN_VALUE: f64 = 0.0;
-/// Maximum allowed value for humidity sensor (%)
-const MAX_VALUE: f64 = 100.0;
+use crate::sensors::policy::{ValidationPolicy, ValueRange};
 
 /// Enumeration representing the unit of humidity measurement.
 ///
@@ -22,6 +19,9 @@ pub enum HumidityUnit {
 }
 
 impl HumidityUnit {
+    /// The unit measurements are stored in.
+    pub const CANONICAL: HumidityUnit = HumidityUnit::Percent;
+
     /// Returns the unit as a string slice.
     ///
     /// # Returns
@@ -72,7 +72,8 @@ impl TryFrom<&str> for HumidityUnit {
 ///
 /// * `device_id` - Unique identifier for the device
 /// * `timestamp` - Measurement time (UTC)
-/// * `value` - Humidity percentage (0.0 to 100.0%)
+/// * `value` - Humidity percentage (0.0 to 100.0% unless the validation
+///   policy sets another range)
 /// * `unit` - Unit of measurement
 ///
 /// # Examples
@@ -100,7 +101,10 @@ pub struct HumiditySensor {
 }
 
 impl HumiditySensor {
-    /// Creates a new HumiditySensor instance.
+    /// Default range of allowed values (0.0 to 100.0%)
+    pub const DEFAULT_RANGE: ValueRange = ValueRange::new(0.0, 100.0);
+
+    /// Creates a new HumiditySensor instance validated against the default range.
     ///
     /// # Arguments
     ///
@@ -123,22 +127,44 @@ impl HumiditySensor {
         timestamp: DateTime<Utc>,
         value: f64,
         unit: HumidityUnit,
+    ) -> Result<Self, SensorValidationError> {
+        Self::with_policy(
+            device_id,
+            timestamp,
+            value,
+            unit,
+            &ValidationPolicy::default(),
+            &SystemClock,
+        )
+    }
+
+    /// Creates a new HumiditySensor instance validated against the range the policy
+    /// sets for the device.
+    ///
+    /// Timestamps are checked against `clock` and the timestamp rules of the
+    /// policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as `new`, and:
+    ///
+    /// * `SensorValidationError::StaleTimestamp` - If the policy sets a maximum
+    ///   age and the timestamp is older
+    pub fn with_policy(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: HumidityUnit,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
     ) -> Result<Self, SensorValidationError> {
         if device_id.is_empty() {
             return Err(SensorValidationError::EmptyDeviceId);
         }
 
-        if timestamp > Utc::now() {
-            return Err(SensorValidationError::FutureTimestamp);
-        }
+        policy.timestamps.check(timestamp, clock.now())?;
 
-        if !(MIN_VALUE..=MAX_VALUE).contains(&value) {
-            return Err(SensorValidationError::ValueOutOfRange {
-                value,
-                min: MIN_VALUE,
-                max: MAX_VALUE,
-            });
-        }
+        Self::validate_value(value, policy.limits_for(&device_id).humidity)?;
 
         Ok(Self {
             device_id,
@@ -148,6 +174,23 @@ impl HumiditySensor {
         })
     }
 
+    /// Checks that a value lies within the given range.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn validate_value(value: f64, range: ValueRange) -> Result<(), SensorValidationError> {
+        if !range.contains(value) {
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: range.min,
+                max: range.max,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Returns the device ID.
     pub fn device_id(&self) -> &str {
         &self.device_id
@@ -0,0 +1,293 @@
+//! Illuminance Sensor Module
+//!
+//! Provides structures and validation for illuminance (ambient light) sensor
+//! data.
+
+use chrono::{DateTime, Utc};
+
+use crate::clock::{Clock, SystemClock};
+use crate::sensors::error::SensorValidationError;
+use crate::sensors::policy::{ValidationPolicy, ValueRange};
+
+/// Enumeration representing the unit of illuminance measurement.
+///
+/// # Variants
+///
+/// * `Lux` - Lux (lumens per square meter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IlluminanceUnit {
+    Lux,
+}
+
+impl IlluminanceUnit {
+    /// The unit measurements are stored in.
+    pub const CANONICAL: IlluminanceUnit = IlluminanceUnit::Lux;
+
+    /// Returns the unit as a string slice.
+    ///
+    /// # Returns
+    ///
+    /// A static string slice representing the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::sensors::illuminance::IlluminanceUnit;
+    ///
+    /// let unit = IlluminanceUnit::Lux;
+    /// assert_eq!(unit.as_str(), "lux");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IlluminanceUnit::Lux => "lux",
+        }
+    }
+}
+
+impl TryFrom<&str> for IlluminanceUnit {
+    type Error = SensorValidationError;
+
+    /// Attempts to convert a string to IlluminanceUnit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The string to convert (case-insensitive, accepts "lux" and
+    ///   the symbol "lx")
+    ///
+    /// # Returns
+    ///
+    /// `Ok(IlluminanceUnit)` on success, `Err(SensorValidationError::InvalidUnit)` on failure.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "lux" | "lx" => Ok(IlluminanceUnit::Lux),
+            _ => Err(SensorValidationError::InvalidUnit(value.to_string())),
+        }
+    }
+}
+
+/// Structure representing illuminance sensor data.
+///
+/// Holds illuminance data collected from IoT devices.
+/// Validation is performed during instance creation.
+///
+/// # Fields
+///
+/// * `device_id` - Unique identifier for the device
+/// * `timestamp` - Measurement time (UTC)
+/// * `value` - Illuminance (0.0 to 200,000.0 lux unless the validation policy
+///   sets another range)
+/// * `unit` - Unit of measurement
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::sensors::illuminance::{IlluminanceSensor, IlluminanceUnit};
+///
+/// let sensor = IlluminanceSensor::new(
+///     "device-001".to_string(),
+///     Utc::now(),
+///     500.0,
+///     IlluminanceUnit::Lux,
+/// ).expect("Valid sensor data");
+///
+/// assert_eq!(sensor.device_id(), "device-001");
+/// assert_eq!(sensor.value(), 500.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IlluminanceSensor {
+    device_id: String,
+    timestamp: DateTime<Utc>,
+    value: f64,
+    unit: IlluminanceUnit,
+}
+
+impl IlluminanceSensor {
+    /// Default range of allowed values (0.0 to 200,000.0 lux)
+    pub const DEFAULT_RANGE: ValueRange = ValueRange::new(0.0, 200_000.0);
+
+    /// Creates a new IlluminanceSensor instance validated against the default
+    /// range.
+    ///
+    /// # Arguments
+    ///
+    /// * `device_id` - Unique identifier for the device (must not be empty)
+    /// * `timestamp` - Measurement time (must not be in the future)
+    /// * `value` - Illuminance (0.0 to 200,000.0 lux unless the validation
+    ///   policy sets another range)
+    /// * `unit` - Unit of measurement
+    ///
+    /// # Returns
+    ///
+    /// `Ok(IlluminanceSensor)` on success, `Err(SensorValidationError)` on validation failure.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::EmptyDeviceId` - If device_id is empty
+    /// * `SensorValidationError::FutureTimestamp` - If timestamp is in the future
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn new(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: IlluminanceUnit,
+    ) -> Result<Self, SensorValidationError> {
+        Self::with_policy(
+            device_id,
+            timestamp,
+            value,
+            unit,
+            &ValidationPolicy::default(),
+            &SystemClock,
+        )
+    }
+
+    /// Creates a new IlluminanceSensor instance validated against the range
+    /// the policy sets for the device.
+    ///
+    /// Timestamps are checked against `clock` and the timestamp rules of the
+    /// policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as `new`, and:
+    ///
+    /// * `SensorValidationError::StaleTimestamp` - If the policy sets a maximum
+    ///   age and the timestamp is older
+    pub fn with_policy(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: IlluminanceUnit,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
+    ) -> Result<Self, SensorValidationError> {
+        if device_id.is_empty() {
+            return Err(SensorValidationError::EmptyDeviceId);
+        }
+
+        policy.timestamps.check(timestamp, clock.now())?;
+
+        Self::validate_value(value, policy.limits_for(&device_id).illuminance)?;
+
+        Ok(Self {
+            device_id,
+            timestamp,
+            value,
+            unit,
+        })
+    }
+
+    /// Checks that a value lies within the given range.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn validate_value(value: f64, range: ValueRange) -> Result<(), SensorValidationError> {
+        if !range.contains(value) {
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: range.min,
+                max: range.max,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the device ID.
+    pub fn device_id(&self) -> &str {
+        &self.device_id
+    }
+
+    /// Returns the measurement timestamp.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Returns the illuminance value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit of measurement.
+    pub fn unit(&self) -> IlluminanceUnit {
+        self.unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sensor(value: f64) -> Result<IlluminanceSensor, SensorValidationError> {
+        IlluminanceSensor::new(
+            "device-001".to_string(),
+            Utc::now(),
+            value,
+            IlluminanceUnit::Lux,
+        )
+    }
+
+    mod illuminance_sensor_new {
+        use super::*;
+
+        #[test]
+        fn success_with_valid_data() {
+            let result = sensor(500.0);
+
+            assert!(result.is_ok());
+            let sensor = result.unwrap();
+            assert_eq!(sensor.device_id(), "device-001");
+            assert_eq!(sensor.value(), 500.0);
+            assert_eq!(sensor.unit(), IlluminanceUnit::Lux);
+        }
+
+        #[test]
+        fn fails_with_empty_device_id() {
+            let result =
+                IlluminanceSensor::new("".to_string(), Utc::now(), 500.0, IlluminanceUnit::Lux);
+
+            assert_eq!(result, Err(SensorValidationError::EmptyDeviceId));
+        }
+
+        #[test]
+        fn fails_with_value_out_of_range() {
+            for value in [-0.5, 200_001.0] {
+                assert!(matches!(
+                    sensor(value),
+                    Err(SensorValidationError::ValueOutOfRange { .. })
+                ));
+            }
+        }
+
+        #[test]
+        fn success_with_boundary_values() {
+            assert!(sensor(0.0).is_ok());
+            assert!(sensor(200_000.0).is_ok());
+        }
+    }
+
+    mod illuminance_unit {
+        use super::*;
+
+        #[test]
+        fn as_str_returns_lux() {
+            assert_eq!(IlluminanceUnit::Lux.as_str(), "lux");
+        }
+
+        #[test]
+        fn try_from_accepts_name_and_symbol() {
+            for unit in ["lux", "LUX", "lx", "Lx"] {
+                assert_eq!(IlluminanceUnit::try_from(unit), Ok(IlluminanceUnit::Lux));
+            }
+        }
+
+        #[test]
+        fn try_from_invalid_unit() {
+            let result = IlluminanceUnit::try_from("fc");
+
+            assert!(matches!(result, Err(SensorValidationError::InvalidUnit(_))));
+        }
+    }
+}
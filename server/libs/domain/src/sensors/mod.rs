@@ -1,5 +1,14 @@
 pub mod co2;
 pub mod error;
 pub mod humidity;
+pub mod illuminance;
+pub mod normalization;
+pub mod particulate;
+pub mod policy;
+pub mod pressure;
+pub mod registry;
 pub mod sensor;
+pub mod sound_level;
 pub mod temperature;
+pub mod validation;
+pub mod voc;
@@ -0,0 +1,397 @@
+//! Unit Normalization Module
+//!
+//! Rewrites the built-in measurements of a `SensorData` in their canonical
+//! units so stored readings can be compared across devices.
+
+use crate::clock::Clock;
+use crate::entities::{OriginalMeasurement, SensorData, SensorMeasurement};
+use crate::sensors::{
+    co2::CO2Unit,
+    humidity::HumidityUnit,
+    illuminance::IlluminanceUnit,
+    particulate::{ParticulateSensor, ParticulateUnit},
+    policy::ValidationPolicy,
+    pressure::PressureUnit,
+    sound_level::SoundLevelUnit,
+    temperature::TemperatureUnit,
+    validation::{ValidatedSensors, ValidationReport},
+    voc::VocUnit,
+};
+
+/// Whether normalization keeps the value and unit a measurement arrived with.
+///
+/// # Variants
+///
+/// * `Discard` - Only the canonical value and unit are kept
+/// * `Keep` - Converted measurements record the received value and unit in
+///   `SensorMeasurement::original`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OriginalUnits {
+    #[default]
+    Discard,
+    Keep,
+}
+
+/// Validates the sensor data against the policy, with timestamps checked
+/// against `clock`, and returns a copy whose
+/// built-in measurements are expressed in their canonical units, with
+/// canonical unit names.
+///
+/// Additional sensors of a kind registered in the policy are converted to
+/// the unit of their kind. Those of unknown kinds are left untouched.
+///
+/// # Errors
+///
+/// Returns the `ValidationReport` of the data when it is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::clock::SystemClock;
+/// use domain::entities::SensorData;
+/// use domain::sensors::normalization::{OriginalUnits, normalize};
+/// use domain::sensors::policy::ValidationPolicy;
+///
+/// let data = SensorData::new("device-001".to_string(), Utc::now())
+///     .with_temperature(212.0, "f");
+///
+/// let policy = ValidationPolicy::default();
+/// let normalized = normalize(&data, &policy, &SystemClock, OriginalUnits::Keep)
+///     .expect("Valid sensor data");
+/// let temperature = normalized.temperature.unwrap();
+/// assert_eq!(temperature.value, 100.0);
+/// assert_eq!(temperature.unit, "Celsius");
+/// assert_eq!(temperature.original.unwrap().unit, "f");
+/// ```
+pub fn normalize(
+    data: &SensorData,
+    policy: &ValidationPolicy,
+    clock: &dyn Clock,
+    original: OriginalUnits,
+) -> Result<SensorData, ValidationReport> {
+    let sensors = ValidatedSensors::with_policy(data, policy, clock)?;
+
+    let mut normalized = data.clone();
+    normalized.temperature =
+        data.temperature
+            .as_ref()
+            .zip(sensors.temperature)
+            .map(|(m, sensor)| {
+                let unit = TemperatureUnit::CANONICAL;
+                canonical(m, sensor.value_in(unit), unit.as_str(), original)
+            });
+    normalized.humidity = data
+        .humidity
+        .as_ref()
+        .zip(sensors.humidity)
+        .map(|(m, sensor)| {
+            canonical(
+                m,
+                sensor.value(),
+                HumidityUnit::CANONICAL.as_str(),
+                original,
+            )
+        });
+    normalized.co2 = data
+        .co2
+        .as_ref()
+        .zip(sensors.co2)
+        .map(|(m, sensor)| canonical(m, sensor.value(), CO2Unit::CANONICAL.as_str(), original));
+    normalized.pressure = data
+        .pressure
+        .as_ref()
+        .zip(sensors.pressure)
+        .map(|(m, sensor)| {
+            let unit = PressureUnit::CANONICAL;
+            canonical(m, sensor.value_in(unit), unit.as_str(), original)
+        });
+    let particulate = |m: &SensorMeasurement, sensor: ParticulateSensor| {
+        canonical(
+            m,
+            sensor.value(),
+            ParticulateUnit::CANONICAL.as_str(),
+            original,
+        )
+    };
+    normalized.pm1_0 = data
+        .pm1_0
+        .as_ref()
+        .zip(sensors.pm1_0)
+        .map(|(m, s)| particulate(m, s));
+    normalized.pm2_5 = data
+        .pm2_5
+        .as_ref()
+        .zip(sensors.pm2_5)
+        .map(|(m, s)| particulate(m, s));
+    normalized.pm10 = data
+        .pm10
+        .as_ref()
+        .zip(sensors.pm10)
+        .map(|(m, s)| particulate(m, s));
+    normalized.voc = data
+        .voc
+        .as_ref()
+        .zip(sensors.voc)
+        .map(|(m, sensor)| canonical(m, sensor.value(), VocUnit::CANONICAL.as_str(), original));
+    normalized.illuminance =
+        data.illuminance
+            .as_ref()
+            .zip(sensors.illuminance)
+            .map(|(m, sensor)| {
+                canonical(
+                    m,
+                    sensor.value(),
+                    IlluminanceUnit::CANONICAL.as_str(),
+                    original,
+                )
+            });
+    normalized.sound_level =
+        data.sound_level
+            .as_ref()
+            .zip(sensors.sound_level)
+            .map(|(m, sensor)| {
+                canonical(
+                    m,
+                    sensor.value(),
+                    SoundLevelUnit::CANONICAL.as_str(),
+                    original,
+                )
+            });
+    for (name, m) in normalized.additional_sensors.iter_mut() {
+        if let Some(kind) = policy.additional_sensors.get(name)
+            && let Ok(value) = kind.to_canonical(m.value, &m.unit)
+        {
+            *m = canonical(m, value, &kind.unit, original);
+        }
+    }
+    Ok(normalized)
+}
+
+/// Builds the canonical form of a measurement, recording the received value
+/// and unit when they changed and `original` asks for it.
+fn canonical(
+    received: &SensorMeasurement,
+    value: f64,
+    unit: &str,
+    original: OriginalUnits,
+) -> SensorMeasurement {
+    let changed = received.value != value || received.unit != unit;
+    let original = match original {
+        OriginalUnits::Keep if changed => Some(OriginalMeasurement {
+            value: received.value,
+            unit: received.unit.clone(),
+        }),
+        _ => None,
+    };
+    SensorMeasurement {
+        value,
+        unit: unit.to_string(),
+        original,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::SystemClock;
+    use crate::sensors::{
+        policy::ValueRange,
+        registry::{SensorKind, SensorRegistry},
+    };
+    use chrono::Utc;
+
+    fn data() -> SensorData {
+        SensorData::new("device-001".to_string(), Utc::now())
+    }
+
+    #[test]
+    fn converts_temperature_to_celsius() {
+        let normalized = normalize(
+            &data().with_temperature(300.0, "kelvin"),
+            &ValidationPolicy::default(),
+            &SystemClock,
+            OriginalUnits::Discard,
+        )
+        .unwrap();
+
+        let temperature = normalized.temperature.unwrap();
+        assert!((temperature.value - 26.85).abs() < 1e-9);
+        assert_eq!(temperature.unit, "Celsius");
+        assert_eq!(temperature.original, None);
+    }
+
+    #[test]
+    fn converts_pressure_to_hpa() {
+        let normalized = normalize(
+            &data().with_pressure(101.325, "kPa"),
+            &ValidationPolicy::default(),
+            &SystemClock,
+            OriginalUnits::Keep,
+        )
+        .unwrap();
+
+        let pressure = normalized.pressure.unwrap();
+        assert!((pressure.value - 1013.25).abs() < 1e-9);
+        assert_eq!(pressure.unit, "hPa");
+        assert_eq!(pressure.original.unwrap().unit, "kPa");
+    }
+
+    #[test]
+    fn renames_units_to_canonical_spelling() {
+        let normalized = normalize(
+            &data()
+                .with_temperature(21.5, "celsius")
+                .with_humidity(40.0, "%")
+                .with_co2(420.0, "PPM"),
+            &ValidationPolicy::default(),
+            &SystemClock,
+            OriginalUnits::Discard,
+        )
+        .unwrap();
+
+        assert_eq!(normalized.temperature.unwrap().unit, "Celsius");
+        assert_eq!(normalized.humidity.unwrap().unit, "Percent");
+        let co2 = normalized.co2.unwrap();
+        assert_eq!(co2.value, 420.0);
+        assert_eq!(co2.unit, "ppm");
+    }
+
+    #[test]
+    fn renames_particulate_units_to_canonical_spelling() {
+        let normalized = normalize(
+            &data().with_pm2_5(12.0, "ug/m3").with_pm10(20.0, "µg/m³"),
+            &ValidationPolicy::default(),
+            &SystemClock,
+            OriginalUnits::Keep,
+        )
+        .unwrap();
+
+        let pm2_5 = normalized.pm2_5.unwrap();
+        assert_eq!(pm2_5.value, 12.0);
+        assert_eq!(pm2_5.unit, "µg/m³");
+        assert_eq!(pm2_5.original.unwrap().unit, "ug/m3");
+        assert_eq!(normalized.pm10.unwrap().original, None);
+        assert!(normalized.pm1_0.is_none());
+    }
+
+    #[test]
+    fn renames_office_environment_units_to_canonical_spelling() {
+        let normalized = normalize(
+            &data()
+                .with_voc(250.0, "PPB")
+                .with_illuminance(500.0, "lx")
+                .with_sound_level(42.5, "dB(A)"),
+            &ValidationPolicy::default(),
+            &SystemClock,
+            OriginalUnits::Discard,
+        )
+        .unwrap();
+
+        assert_eq!(normalized.voc.unwrap().unit, "ppb");
+        assert_eq!(normalized.illuminance.unwrap().unit, "lux");
+        let sound_level = normalized.sound_level.unwrap();
+        assert_eq!(sound_level.value, 42.5);
+        assert_eq!(sound_level.unit, "dBA");
+    }
+
+    #[test]
+    fn keeps_original_of_converted_measurements() {
+        let normalized = normalize(
+            &data().with_temperature(77.0, "F").with_co2(420.0, "ppm"),
+            &ValidationPolicy::default(),
+            &SystemClock,
+            OriginalUnits::Keep,
+        )
+        .unwrap();
+
+        assert_eq!(
+            normalized.temperature.unwrap().original,
+            Some(OriginalMeasurement {
+                value: 77.0,
+                unit: "F".to_string(),
+            })
+        );
+        // 既に正規形の測定値には元の値を記録しない
+        assert_eq!(normalized.co2.unwrap().original, None);
+    }
+
+    #[test]
+    fn leaves_additional_sensors_untouched() {
+        let normalized = normalize(
+            &data().with_additional_sensor("wind_speed", 7.8, "mph"),
+            &ValidationPolicy::default(),
+            &SystemClock,
+            OriginalUnits::Keep,
+        )
+        .unwrap();
+
+        let wind_speed = &normalized.additional_sensors["wind_speed"];
+        assert_eq!(wind_speed.value, 7.8);
+        assert_eq!(wind_speed.unit, "mph");
+        assert_eq!(wind_speed.original, None);
+    }
+
+    #[test]
+    fn converts_registered_additional_sensors() {
+        let policy = ValidationPolicy {
+            additional_sensors: SensorRegistry::new().with_kind(
+                "wind_speed",
+                SensorKind::new("m/s", ValueRange::new(0.0, 100.0)).with_unit("km/h", 0.25),
+            ),
+            ..ValidationPolicy::default()
+        };
+
+        let normalized = normalize(
+            &data()
+                .with_additional_sensor("wind_speed", 14.0, "KM/H")
+                .with_additional_sensor("water_level", -3.5, "cm"),
+            &policy,
+            &SystemClock,
+            OriginalUnits::Keep,
+        )
+        .unwrap();
+
+        let wind_speed = &normalized.additional_sensors["wind_speed"];
+        assert_eq!(wind_speed.value, 3.5);
+        assert_eq!(wind_speed.unit, "m/s");
+        assert_eq!(wind_speed.original.as_ref().unwrap().unit, "KM/H");
+        assert_eq!(normalized.additional_sensors["water_level"].unit, "cm");
+    }
+
+    #[test]
+    fn fails_with_invalid_data() {
+        let report = normalize(
+            &data().with_humidity(50.0, "ratio"),
+            &ValidationPolicy::default(),
+            &SystemClock,
+            OriginalUnits::Keep,
+        )
+        .unwrap_err();
+
+        assert_eq!(report.errors()[0].path, "humidity.unit");
+    }
+
+    #[test]
+    fn validates_against_policy() {
+        let policy: ValidationPolicy = serde_json::from_value(serde_json::json!({
+            "devices": { "freezer-01": { "temperature": { "min": -80.0, "max": 0.0 } } },
+        }))
+        .unwrap();
+        let data =
+            SensorData::new("freezer-01".to_string(), Utc::now()).with_temperature(-76.0, "F");
+
+        let normalized = normalize(&data, &policy, &SystemClock, OriginalUnits::Discard).unwrap();
+
+        assert!((normalized.temperature.unwrap().value + 60.0).abs() < 1e-9);
+        assert!(
+            normalize(
+                &data,
+                &ValidationPolicy::default(),
+                &SystemClock,
+                OriginalUnits::Discard
+            )
+            .is_err()
+        );
+    }
+}
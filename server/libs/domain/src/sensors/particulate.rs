@@ -0,0 +1,447 @@
+//! Particulate Matter Sensor Module
+//!
+//! Provides structures and validation for particulate matter (PM1.0, PM2.5
+//! and PM10) concentration sensor data.
+
+use chrono::{DateTime, Utc};
+
+use crate::clock::{Clock, SystemClock};
+use crate::sensors::error::SensorValidationError;
+use crate::sensors::policy::{SensorLimits, ValidationPolicy, ValueRange};
+
+/// Enumeration representing the particle size class of a measurement.
+///
+/// # Variants
+///
+/// * `Pm1_0` - Particles with a diameter of 1.0 µm or less
+/// * `Pm2_5` - Particles with a diameter of 2.5 µm or less
+/// * `Pm10` - Particles with a diameter of 10 µm or less
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParticleSize {
+    Pm1_0,
+    Pm2_5,
+    Pm10,
+}
+
+impl ParticleSize {
+    /// Every size class, from finest to coarsest.
+    pub const ALL: [ParticleSize; 3] =
+        [ParticleSize::Pm1_0, ParticleSize::Pm2_5, ParticleSize::Pm10];
+
+    /// Returns the name of the `SensorData` field holding this size class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::sensors::particulate::ParticleSize;
+    ///
+    /// assert_eq!(ParticleSize::Pm2_5.as_str(), "pm2_5");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParticleSize::Pm1_0 => "pm1_0",
+            ParticleSize::Pm2_5 => "pm2_5",
+            ParticleSize::Pm10 => "pm10",
+        }
+    }
+
+    /// Returns the range that applies to this size class.
+    pub fn range(&self, limits: &SensorLimits) -> ValueRange {
+        match self {
+            ParticleSize::Pm1_0 => limits.pm1_0,
+            ParticleSize::Pm2_5 => limits.pm2_5,
+            ParticleSize::Pm10 => limits.pm10,
+        }
+    }
+}
+
+/// Enumeration representing the unit of particulate matter measurement.
+///
+/// # Variants
+///
+/// * `MicrogramsPerCubicMeter` - Micrograms per cubic meter (µg/m³)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticulateUnit {
+    MicrogramsPerCubicMeter,
+}
+
+impl ParticulateUnit {
+    /// The unit measurements are stored in.
+    pub const CANONICAL: ParticulateUnit = ParticulateUnit::MicrogramsPerCubicMeter;
+
+    /// Returns the unit as a string slice.
+    ///
+    /// # Returns
+    ///
+    /// A static string slice representing the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::sensors::particulate::ParticulateUnit;
+    ///
+    /// let unit = ParticulateUnit::MicrogramsPerCubicMeter;
+    /// assert_eq!(unit.as_str(), "µg/m³");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParticulateUnit::MicrogramsPerCubicMeter => "µg/m³",
+        }
+    }
+}
+
+impl TryFrom<&str> for ParticulateUnit {
+    type Error = SensorValidationError;
+
+    /// Attempts to convert a string to ParticulateUnit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The string to convert (case-insensitive, accepts "µg/m³"
+    ///   and the ASCII spelling "ug/m3")
+    ///
+    /// # Returns
+    ///
+    /// `Ok(ParticulateUnit)` on success, `Err(SensorValidationError::InvalidUnit)` on failure.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // マイクロ記号 (U+00B5) とギリシャ文字ミュー (U+03BC) の両方を受け付ける
+        let normalized = value
+            .to_lowercase()
+            .replace(['µ', 'μ'], "u")
+            .replace('³', "3");
+        match normalized.as_str() {
+            "ug/m3" => Ok(ParticulateUnit::MicrogramsPerCubicMeter),
+            _ => Err(SensorValidationError::InvalidUnit(value.to_string())),
+        }
+    }
+}
+
+/// Structure representing particulate matter sensor data.
+///
+/// Holds the concentration of one particle size class collected from IoT
+/// devices. Validation is performed during instance creation.
+///
+/// # Fields
+///
+/// * `device_id` - Unique identifier for the device
+/// * `timestamp` - Measurement time (UTC)
+/// * `size` - Particle size class
+/// * `value` - Concentration (0.0 to 1,000.0 µg/m³ unless the validation
+///   policy sets another range)
+/// * `unit` - Unit of measurement
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::sensors::particulate::{ParticleSize, ParticulateSensor, ParticulateUnit};
+///
+/// let sensor = ParticulateSensor::new(
+///     "device-001".to_string(),
+///     Utc::now(),
+///     ParticleSize::Pm2_5,
+///     12.0,
+///     ParticulateUnit::MicrogramsPerCubicMeter,
+/// ).expect("Valid sensor data");
+///
+/// assert_eq!(sensor.size(), ParticleSize::Pm2_5);
+/// assert_eq!(sensor.value(), 12.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticulateSensor {
+    device_id: String,
+    timestamp: DateTime<Utc>,
+    size: ParticleSize,
+    value: f64,
+    unit: ParticulateUnit,
+}
+
+impl ParticulateSensor {
+    /// Default range of allowed values for every size class (0.0 to 1,000.0 µg/m³)
+    pub const DEFAULT_RANGE: ValueRange = ValueRange::new(0.0, 1_000.0);
+
+    /// Creates a new ParticulateSensor instance validated against the default
+    /// range.
+    ///
+    /// # Arguments
+    ///
+    /// * `device_id` - Unique identifier for the device (must not be empty)
+    /// * `timestamp` - Measurement time (must not be in the future)
+    /// * `size` - Particle size class
+    /// * `value` - Concentration (0.0 to 1,000.0 µg/m³ unless the validation
+    ///   policy sets another range)
+    /// * `unit` - Unit of measurement
+    ///
+    /// # Returns
+    ///
+    /// `Ok(ParticulateSensor)` on success, `Err(SensorValidationError)` on validation failure.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::EmptyDeviceId` - If device_id is empty
+    /// * `SensorValidationError::FutureTimestamp` - If timestamp is in the future
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn new(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        size: ParticleSize,
+        value: f64,
+        unit: ParticulateUnit,
+    ) -> Result<Self, SensorValidationError> {
+        Self::with_policy(
+            device_id,
+            timestamp,
+            size,
+            value,
+            unit,
+            &ValidationPolicy::default(),
+            &SystemClock,
+        )
+    }
+
+    /// Creates a new ParticulateSensor instance validated against the range
+    /// the policy sets for the device and size class.
+    ///
+    /// Timestamps are checked against `clock` and the timestamp rules of the
+    /// policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as `new`, and:
+    ///
+    /// * `SensorValidationError::StaleTimestamp` - If the policy sets a maximum
+    ///   age and the timestamp is older
+    pub fn with_policy(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        size: ParticleSize,
+        value: f64,
+        unit: ParticulateUnit,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
+    ) -> Result<Self, SensorValidationError> {
+        if device_id.is_empty() {
+            return Err(SensorValidationError::EmptyDeviceId);
+        }
+
+        policy.timestamps.check(timestamp, clock.now())?;
+
+        Self::validate_value(value, size.range(&policy.limits_for(&device_id)))?;
+
+        Ok(Self {
+            device_id,
+            timestamp,
+            size,
+            value,
+            unit,
+        })
+    }
+
+    /// Checks that a value lies within the given range.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn validate_value(value: f64, range: ValueRange) -> Result<(), SensorValidationError> {
+        if !range.contains(value) {
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: range.min,
+                max: range.max,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the device ID.
+    pub fn device_id(&self) -> &str {
+        &self.device_id
+    }
+
+    /// Returns the measurement timestamp.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Returns the particle size class.
+    pub fn size(&self) -> ParticleSize {
+        self.size
+    }
+
+    /// Returns the concentration value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit of measurement.
+    pub fn unit(&self) -> ParticulateUnit {
+        self.unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensors::policy::{DeviceLimits, LimitOverrides};
+
+    fn sensor(size: ParticleSize, value: f64) -> Result<ParticulateSensor, SensorValidationError> {
+        ParticulateSensor::new(
+            "device-001".to_string(),
+            Utc::now(),
+            size,
+            value,
+            ParticulateUnit::MicrogramsPerCubicMeter,
+        )
+    }
+
+    mod particulate_sensor_new {
+        use super::*;
+
+        #[test]
+        fn success_with_valid_data() {
+            let result = sensor(ParticleSize::Pm2_5, 12.0);
+
+            assert!(result.is_ok());
+            let sensor = result.unwrap();
+            assert_eq!(sensor.device_id(), "device-001");
+            assert_eq!(sensor.size(), ParticleSize::Pm2_5);
+            assert_eq!(sensor.value(), 12.0);
+            assert_eq!(sensor.unit(), ParticulateUnit::MicrogramsPerCubicMeter);
+        }
+
+        #[test]
+        fn fails_with_empty_device_id() {
+            let result = ParticulateSensor::new(
+                "".to_string(),
+                Utc::now(),
+                ParticleSize::Pm10,
+                20.0,
+                ParticulateUnit::MicrogramsPerCubicMeter,
+            );
+
+            assert_eq!(result, Err(SensorValidationError::EmptyDeviceId));
+        }
+
+        #[test]
+        fn fails_with_future_timestamp() {
+            let future = Utc::now() + chrono::Duration::hours(1);
+
+            let result = ParticulateSensor::new(
+                "device-001".to_string(),
+                future,
+                ParticleSize::Pm10,
+                20.0,
+                ParticulateUnit::MicrogramsPerCubicMeter,
+            );
+
+            assert_eq!(result, Err(SensorValidationError::FutureTimestamp));
+        }
+
+        #[test]
+        fn fails_with_negative_value() {
+            for size in ParticleSize::ALL {
+                assert!(matches!(
+                    sensor(size, -0.1),
+                    Err(SensorValidationError::ValueOutOfRange { .. })
+                ));
+            }
+        }
+
+        #[test]
+        fn fails_with_value_above_max() {
+            let result = sensor(ParticleSize::Pm10, 1_000.1);
+
+            assert!(matches!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange { .. })
+            ));
+        }
+
+        #[test]
+        fn success_with_boundary_values() {
+            assert!(sensor(ParticleSize::Pm1_0, 0.0).is_ok());
+            assert!(sensor(ParticleSize::Pm1_0, 1_000.0).is_ok());
+        }
+    }
+
+    mod particulate_sensor_with_policy {
+        use super::*;
+        use crate::clock::SystemClock;
+
+        #[test]
+        fn uses_the_range_of_the_size_class() {
+            let mut policy = ValidationPolicy::default();
+            policy.devices.insert(
+                "device-001".to_string(),
+                DeviceLimits {
+                    group: None,
+                    limits: LimitOverrides {
+                        pm2_5: Some(ValueRange::new(0.0, 35.0)),
+                        ..LimitOverrides::default()
+                    },
+                },
+            );
+            let build = |size| {
+                ParticulateSensor::with_policy(
+                    "device-001".to_string(),
+                    Utc::now(),
+                    size,
+                    50.0,
+                    ParticulateUnit::MicrogramsPerCubicMeter,
+                    &policy,
+                    &SystemClock,
+                )
+            };
+
+            assert_eq!(
+                build(ParticleSize::Pm2_5),
+                Err(SensorValidationError::ValueOutOfRange {
+                    value: 50.0,
+                    min: 0.0,
+                    max: 35.0,
+                })
+            );
+            assert!(build(ParticleSize::Pm10).is_ok());
+        }
+    }
+
+    mod particulate_unit {
+        use super::*;
+
+        #[test]
+        fn as_str_returns_micrograms_per_cubic_meter() {
+            assert_eq!(ParticulateUnit::MicrogramsPerCubicMeter.as_str(), "µg/m³");
+        }
+
+        #[test]
+        fn try_from_accepts_common_spellings() {
+            for unit in ["µg/m³", "μg/m³", "ug/m3", "UG/M3", "µg/m3"] {
+                assert_eq!(
+                    ParticulateUnit::try_from(unit),
+                    Ok(ParticulateUnit::MicrogramsPerCubicMeter),
+                    "{}",
+                    unit
+                );
+            }
+        }
+
+        #[test]
+        fn try_from_invalid_unit() {
+            let result = ParticulateUnit::try_from("mg/m3");
+
+            assert!(matches!(result, Err(SensorValidationError::InvalidUnit(_))));
+        }
+    }
+
+    mod particle_size {
+        use super::*;
+
+        #[test]
+        fn as_str_returns_field_names() {
+            let names: Vec<&str> = ParticleSize::ALL.iter().map(|s| s.as_str()).collect();
+
+            assert_eq!(names, ["pm1_0", "pm2_5", "pm10"]);
+        }
+    }
+}
@@ -0,0 +1,538 @@
+//! Validation Policy Module
+//!
+//! Provides the value ranges the built-in sensors are validated against:
+//! defaults per sensor kind, overridden per device group and per device.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::sensors::{
+    co2::CO2Sensor, error::SensorValidationError, humidity::HumiditySensor,
+    illuminance::IlluminanceSensor, particulate::ParticulateSensor, pressure::PressureSensor,
+    registry::SensorRegistry, sound_level::SoundLevelSensor, temperature::TemperatureSensor,
+    voc::VocSensor,
+};
+
+/// Slack allowed when comparing converted values against a range, so that
+/// a bound expressed in another unit is not rejected by rounding.
+const TOLERANCE: f64 = 1e-9;
+
+/// An inclusive range of allowed values, in the canonical unit of the
+/// sensor kind it applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ValueRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ValueRange {
+    /// Creates a new ValueRange.
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns `true` when `value` lies within the range.
+    pub fn contains(&self, value: f64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    /// Returns `true` when `value` lies within the range, allowing for the
+    /// rounding error of a unit conversion.
+    pub fn contains_with_tolerance(&self, value: f64) -> bool {
+        (self.min - TOLERANCE..=self.max + TOLERANCE).contains(&value)
+    }
+
+    /// Returns the range with both bounds rounded to hundredths, so that
+    /// converted limits read cleanly in error messages.
+    pub fn rounded(&self) -> Self {
+        Self::new(
+            (self.min * 100.0).round() / 100.0,
+            (self.max * 100.0).round() / 100.0,
+        )
+    }
+
+    /// Returns `true` when `min` is not greater than `max`.
+    pub fn is_valid(&self) -> bool {
+        self.min <= self.max
+    }
+}
+
+/// The range of every built-in sensor kind.
+///
+/// Temperature is in degrees Celsius, humidity in percent, CO2 in ppm,
+/// pressure in hPa, particulate matter in µg/m³, TVOC in ppb, illuminance
+/// in lux and sound level in dBA.
+/// Kinds missing from a configuration file keep their default range.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SensorLimits {
+    pub temperature: ValueRange,
+    pub humidity: ValueRange,
+    pub co2: ValueRange,
+    pub pressure: ValueRange,
+    pub pm1_0: ValueRange,
+    pub pm2_5: ValueRange,
+    pub pm10: ValueRange,
+    pub voc: ValueRange,
+    pub illuminance: ValueRange,
+    pub sound_level: ValueRange,
+}
+
+impl Default for SensorLimits {
+    fn default() -> Self {
+        Self {
+            temperature: TemperatureSensor::DEFAULT_RANGE,
+            humidity: HumiditySensor::DEFAULT_RANGE,
+            co2: CO2Sensor::DEFAULT_RANGE,
+            pressure: PressureSensor::DEFAULT_RANGE,
+            pm1_0: ParticulateSensor::DEFAULT_RANGE,
+            pm2_5: ParticulateSensor::DEFAULT_RANGE,
+            pm10: ParticulateSensor::DEFAULT_RANGE,
+            voc: VocSensor::DEFAULT_RANGE,
+            illuminance: IlluminanceSensor::DEFAULT_RANGE,
+            sound_level: SoundLevelSensor::DEFAULT_RANGE,
+        }
+    }
+}
+
+impl SensorLimits {
+    /// Returns these limits with the ranges set in `overrides` replaced.
+    pub fn overridden_by(self, overrides: &LimitOverrides) -> Self {
+        Self {
+            temperature: overrides.temperature.unwrap_or(self.temperature),
+            humidity: overrides.humidity.unwrap_or(self.humidity),
+            co2: overrides.co2.unwrap_or(self.co2),
+            pressure: overrides.pressure.unwrap_or(self.pressure),
+            pm1_0: overrides.pm1_0.unwrap_or(self.pm1_0),
+            pm2_5: overrides.pm2_5.unwrap_or(self.pm2_5),
+            pm10: overrides.pm10.unwrap_or(self.pm10),
+            voc: overrides.voc.unwrap_or(self.voc),
+            illuminance: overrides.illuminance.unwrap_or(self.illuminance),
+            sound_level: overrides.sound_level.unwrap_or(self.sound_level),
+        }
+    }
+}
+
+/// Ranges replacing the inherited ones for the kinds that are set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LimitOverrides {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub humidity: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub co2: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pressure: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pm1_0: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pm2_5: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pm10: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voc: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub illuminance: Option<ValueRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sound_level: Option<ValueRange>,
+}
+
+impl LimitOverrides {
+    fn ranges(&self) -> impl Iterator<Item = (&'static str, ValueRange)> {
+        [
+            ("temperature", self.temperature),
+            ("humidity", self.humidity),
+            ("co2", self.co2),
+            ("pressure", self.pressure),
+            ("pm1_0", self.pm1_0),
+            ("pm2_5", self.pm2_5),
+            ("pm10", self.pm10),
+            ("voc", self.voc),
+            ("illuminance", self.illuminance),
+            ("sound_level", self.sound_level),
+        ]
+        .into_iter()
+        .filter_map(|(kind, range)| Some((kind, range?)))
+    }
+}
+
+/// Rules for the timestamp of a reading, relative to the current time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimestampRules {
+    /// Seconds a timestamp may lie in the future, to absorb device clocks
+    /// running slightly fast.
+    pub future_tolerance_secs: u32,
+    /// Maximum age of a reading in days, unlimited when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<u32>,
+}
+
+impl TimestampRules {
+    /// Checks a timestamp against these rules.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::FutureTimestamp` - If timestamp is further in
+    ///   the future than the tolerance
+    /// * `SensorValidationError::StaleTimestamp` - If timestamp is older than
+    ///   the maximum age
+    pub fn check(
+        &self,
+        timestamp: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), SensorValidationError> {
+        let latest = now.checked_add_signed(Duration::seconds(self.future_tolerance_secs.into()));
+        if latest.is_some_and(|latest| timestamp > latest) {
+            return Err(SensorValidationError::FutureTimestamp);
+        }
+
+        if let Some(max_age_days) = self.max_age_days {
+            let oldest = now.checked_sub_signed(Duration::days(max_age_days.into()));
+            if oldest.is_some_and(|oldest| timestamp < oldest) {
+                return Err(SensorValidationError::StaleTimestamp { max_age_days });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Overrides for a single device, optionally inheriting those of a group.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeviceLimits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(flatten)]
+    pub limits: LimitOverrides,
+}
+
+/// The value ranges readings are validated against.
+///
+/// The range of a device is resolved from the defaults, then the overrides
+/// of the device's group, then the device's own overrides. The timestamp
+/// rules and the registry of additional sensor kinds apply to every device.
+///
+/// # Examples
+///
+/// ```
+/// use domain::sensors::policy::ValidationPolicy;
+///
+/// let policy: ValidationPolicy = serde_json::from_str(r#"{
+///     "timestamps": { "future_tolerance_secs": 5, "max_age_days": 30 },
+///     "groups": { "freezer": { "temperature": { "min": -80.0, "max": 0.0 } } },
+///     "devices": { "freezer-01": { "group": "freezer" } }
+/// }"#).unwrap();
+/// policy.check().expect("Valid policy");
+///
+/// assert_eq!(policy.limits_for("freezer-01").temperature.min, -80.0);
+/// assert_eq!(policy.limits_for("device-001").temperature.min, -50.0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidationPolicy {
+    pub defaults: SensorLimits,
+    pub groups: HashMap<String, LimitOverrides>,
+    pub devices: HashMap<String, DeviceLimits>,
+    pub timestamps: TimestampRules,
+    pub additional_sensors: SensorRegistry,
+}
+
+impl ValidationPolicy {
+    /// Returns the ranges that apply to the given device.
+    pub fn limits_for(&self, device_id: &str) -> SensorLimits {
+        let Some(device) = self.devices.get(device_id) else {
+            return self.defaults;
+        };
+        let group = device.group.as_ref().and_then(|g| self.groups.get(g));
+        let limits = match group {
+            Some(group) => self.defaults.overridden_by(group),
+            None => self.defaults,
+        };
+        limits.overridden_by(&device.limits)
+    }
+
+    /// Checks that every range is well-formed, every device refers to an
+    /// existing group and every unit conversion factor is usable.
+    ///
+    /// # Errors
+    ///
+    /// * `PolicyError::InvalidRange` - If a range has `min` greater than `max`
+    /// * `PolicyError::UnknownGroup` - If a device refers to a missing group
+    /// * `PolicyError::InvalidFactor` - If a conversion factor is not a
+    ///   positive finite number
+    pub fn check(&self) -> Result<(), PolicyError> {
+        let defaults = LimitOverrides {
+            temperature: Some(self.defaults.temperature),
+            humidity: Some(self.defaults.humidity),
+            co2: Some(self.defaults.co2),
+            pressure: Some(self.defaults.pressure),
+            pm1_0: Some(self.defaults.pm1_0),
+            pm2_5: Some(self.defaults.pm2_5),
+            pm10: Some(self.defaults.pm10),
+            voc: Some(self.defaults.voc),
+            illuminance: Some(self.defaults.illuminance),
+            sound_level: Some(self.defaults.sound_level),
+        };
+        check_ranges("defaults", &defaults)?;
+        for (name, group) in &self.groups {
+            check_ranges(&format!("groups.{}", name), group)?;
+        }
+        for (device_id, device) in &self.devices {
+            check_ranges(&format!("devices.{}", device_id), &device.limits)?;
+            if let Some(group) = &device.group
+                && !self.groups.contains_key(group)
+            {
+                return Err(PolicyError::UnknownGroup {
+                    device_id: device_id.clone(),
+                    group: group.clone(),
+                });
+            }
+        }
+        for (name, kind) in &self.additional_sensors.kinds {
+            let path = format!("additional_sensors.kinds.{}", name);
+            if !kind.range.is_valid() {
+                return Err(PolicyError::InvalidRange {
+                    path: format!("{}.range", path),
+                    range: kind.range,
+                });
+            }
+            if let Some((unit, factor)) = kind
+                .units
+                .iter()
+                .find(|(_, factor)| !(factor.is_finite() && **factor > 0.0))
+            {
+                return Err(PolicyError::InvalidFactor {
+                    path: format!("{}.units.{}", path, unit),
+                    factor: *factor,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn check_ranges(path: &str, overrides: &LimitOverrides) -> Result<(), PolicyError> {
+    match overrides.ranges().find(|(_, range)| !range.is_valid()) {
+        Some((kind, range)) => Err(PolicyError::InvalidRange {
+            path: format!("{}.{}", path, kind),
+            range,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// A problem found in a `ValidationPolicy`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyError {
+    InvalidRange { path: String, range: ValueRange },
+    UnknownGroup { device_id: String, group: String },
+    InvalidFactor { path: String, factor: f64 },
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyError::InvalidRange { path, range } => {
+                write!(
+                    f,
+                    "{}: min {} must not be greater than max {}",
+                    path, range.min, range.max
+                )
+            }
+            PolicyError::UnknownGroup { device_id, group } => {
+                write!(f, "device {} refers to unknown group {}", device_id, group)
+            }
+            PolicyError::InvalidFactor { path, factor } => {
+                write!(f, "{}: factor {} must be a positive number", path, factor)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn policy(value: serde_json::Value) -> ValidationPolicy {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn defaults_to_sensor_ranges() {
+        let limits = ValidationPolicy::default().limits_for("device-001");
+
+        assert_eq!(limits.temperature, ValueRange::new(-50.0, 150.0));
+        assert_eq!(limits.humidity, ValueRange::new(0.0, 100.0));
+        assert_eq!(limits.co2, ValueRange::new(0.0, 50_000.0));
+    }
+
+    #[test]
+    fn keeps_default_ranges_missing_from_configuration() {
+        let policy = policy(json!({
+            "defaults": { "co2": { "min": 300.0, "max": 5_000.0 } },
+        }));
+
+        let limits = policy.limits_for("device-001");
+        assert_eq!(limits.co2, ValueRange::new(300.0, 5_000.0));
+        assert_eq!(limits.temperature, TemperatureSensor::DEFAULT_RANGE);
+    }
+
+    #[test]
+    fn device_overrides_group_and_group_overrides_defaults() {
+        let policy = policy(json!({
+            "groups": {
+                "greenhouse": {
+                    "temperature": { "min": 5.0, "max": 45.0 },
+                    "humidity": { "min": 30.0, "max": 100.0 },
+                },
+            },
+            "devices": {
+                "greenhouse-07": {
+                    "group": "greenhouse",
+                    "humidity": { "min": 50.0, "max": 100.0 },
+                },
+            },
+        }));
+
+        let limits = policy.limits_for("greenhouse-07");
+        assert_eq!(limits.temperature, ValueRange::new(5.0, 45.0));
+        assert_eq!(limits.humidity, ValueRange::new(50.0, 100.0));
+        assert_eq!(limits.co2, CO2Sensor::DEFAULT_RANGE);
+    }
+
+    mod timestamp_rules_check {
+        use super::*;
+
+        fn now() -> DateTime<Utc> {
+            DateTime::parse_from_rfc3339("2024-06-01T12:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc)
+        }
+
+        #[test]
+        fn rejects_future_timestamp_by_default() {
+            let rules = TimestampRules::default();
+
+            assert_eq!(
+                rules.check(now() + Duration::seconds(1), now()),
+                Err(SensorValidationError::FutureTimestamp)
+            );
+            assert_eq!(rules.check(now(), now()), Ok(()));
+        }
+
+        #[test]
+        fn accepts_future_timestamp_within_tolerance() {
+            let rules = TimestampRules {
+                future_tolerance_secs: 5,
+                ..Default::default()
+            };
+
+            assert_eq!(rules.check(now() + Duration::seconds(5), now()), Ok(()));
+            assert_eq!(
+                rules.check(now() + Duration::seconds(6), now()),
+                Err(SensorValidationError::FutureTimestamp)
+            );
+        }
+
+        #[test]
+        fn rejects_timestamp_older_than_max_age() {
+            let rules = TimestampRules {
+                max_age_days: Some(30),
+                ..Default::default()
+            };
+
+            assert_eq!(rules.check(now() - Duration::days(30), now()), Ok(()));
+            assert_eq!(
+                rules.check(now() - Duration::days(31), now()),
+                Err(SensorValidationError::StaleTimestamp { max_age_days: 30 })
+            );
+        }
+
+        #[test]
+        fn accepts_any_age_without_max_age() {
+            let rules = TimestampRules::default();
+
+            assert_eq!(rules.check(now() - Duration::days(3650), now()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn contains_with_tolerance_accepts_conversion_error() {
+        let range = ValueRange::new(-40.0, 85.0);
+
+        assert!(!range.contains(85.000_000_000_1));
+        assert!(range.contains_with_tolerance(85.000_000_000_1));
+        assert!(!range.contains_with_tolerance(85.01));
+    }
+
+    #[test]
+    fn rounded_rounds_bounds_to_hundredths() {
+        let range = ValueRange::new(-40.004, 185.005_1);
+
+        assert_eq!(range.rounded(), ValueRange::new(-40.0, 185.01));
+    }
+
+    #[test]
+    fn check_rejects_inverted_range() {
+        let policy = policy(json!({
+            "devices": { "freezer-01": { "temperature": { "min": 0.0, "max": -80.0 } } },
+        }));
+
+        assert_eq!(
+            policy.check(),
+            Err(PolicyError::InvalidRange {
+                path: "devices.freezer-01.temperature".to_string(),
+                range: ValueRange::new(0.0, -80.0),
+            })
+        );
+    }
+
+    #[test]
+    fn check_rejects_non_positive_factor() {
+        let policy = policy(json!({
+            "additional_sensors": {
+                "kinds": {
+                    "wind_speed": {
+                        "unit": "m/s",
+                        "range": { "min": 0.0, "max": 100.0 },
+                        "units": { "mph": 0.0 },
+                    },
+                },
+            },
+        }));
+
+        assert_eq!(
+            policy.check(),
+            Err(PolicyError::InvalidFactor {
+                path: "additional_sensors.kinds.wind_speed.units.mph".to_string(),
+                factor: 0.0,
+            })
+        );
+    }
+
+    #[test]
+    fn check_rejects_unknown_group() {
+        let policy = policy(json!({
+            "devices": { "freezer-01": { "group": "freezer" } },
+        }));
+
+        assert_eq!(
+            policy.check(),
+            Err(PolicyError::UnknownGroup {
+                device_id: "freezer-01".to_string(),
+                group: "freezer".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,516 @@
+//! Pressure Sensor Module
+//!
+//! Provides structures and validation for barometric pressure sensor data.
+
+use chrono::{DateTime, Utc};
+
+use crate::clock::{Clock, SystemClock};
+use crate::sensors::error::SensorValidationError;
+use crate::sensors::policy::{ValidationPolicy, ValueRange};
+
+/// Hectopascals in one inch of mercury
+const HPA_PER_INHG: f64 = 33.863_886_666_667;
+/// Hectopascals in one millimetre of mercury
+const HPA_PER_MMHG: f64 = 1.333_223_874_15;
+
+/// Enumeration representing the unit of pressure measurement.
+///
+/// # Variants
+///
+/// * `Hectopascal` - Hectopascals (hPa), equal to millibars
+/// * `Kilopascal` - Kilopascals (kPa)
+/// * `Pascal` - Pascals (Pa)
+/// * `InchOfMercury` - Inches of mercury (inHg)
+/// * `MillimeterOfMercury` - Millimetres of mercury (mmHg)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PressureUnit {
+    Hectopascal,
+    Kilopascal,
+    Pascal,
+    InchOfMercury,
+    MillimeterOfMercury,
+}
+
+impl PressureUnit {
+    /// The unit validation limits are defined in and measurements are stored in.
+    pub const CANONICAL: PressureUnit = PressureUnit::Hectopascal;
+
+    /// Returns the unit as a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::sensors::pressure::PressureUnit;
+    ///
+    /// assert_eq!(PressureUnit::InchOfMercury.as_str(), "inHg");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PressureUnit::Hectopascal => "hPa",
+            PressureUnit::Kilopascal => "kPa",
+            PressureUnit::Pascal => "Pa",
+            PressureUnit::InchOfMercury => "inHg",
+            PressureUnit::MillimeterOfMercury => "mmHg",
+        }
+    }
+
+    /// Converts a value in this unit to hectopascals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::sensors::pressure::PressureUnit;
+    ///
+    /// assert_eq!(PressureUnit::Kilopascal.to_hpa(101.325), 1013.25);
+    /// ```
+    pub fn to_hpa(&self, value: f64) -> f64 {
+        match self {
+            PressureUnit::Hectopascal => value,
+            PressureUnit::Kilopascal => value * 10.0,
+            PressureUnit::Pascal => value / 100.0,
+            PressureUnit::InchOfMercury => value * HPA_PER_INHG,
+            PressureUnit::MillimeterOfMercury => value * HPA_PER_MMHG,
+        }
+    }
+
+    /// Converts a value in hectopascals to this unit.
+    pub fn from_hpa(&self, value: f64) -> f64 {
+        match self {
+            PressureUnit::Hectopascal => value,
+            PressureUnit::Kilopascal => value / 10.0,
+            PressureUnit::Pascal => value * 100.0,
+            PressureUnit::InchOfMercury => value / HPA_PER_INHG,
+            PressureUnit::MillimeterOfMercury => value / HPA_PER_MMHG,
+        }
+    }
+
+    /// Converts a value in this unit to another unit.
+    pub fn convert(&self, value: f64, to: PressureUnit) -> f64 {
+        if *self == to {
+            return value;
+        }
+        to.from_hpa(self.to_hpa(value))
+    }
+}
+
+impl TryFrom<&str> for PressureUnit {
+    type Error = SensorValidationError;
+
+    /// Attempts to convert a string to PressureUnit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The string to convert (case-insensitive, accepts "hPa", "kPa",
+    ///   "Pa", "inHg" or "mmHg")
+    ///
+    /// # Returns
+    ///
+    /// `Ok(PressureUnit)` on success, `Err(SensorValidationError::InvalidUnit)` on failure.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "hpa" => Ok(PressureUnit::Hectopascal),
+            "kpa" => Ok(PressureUnit::Kilopascal),
+            "pa" => Ok(PressureUnit::Pascal),
+            "inhg" => Ok(PressureUnit::InchOfMercury),
+            "mmhg" => Ok(PressureUnit::MillimeterOfMercury),
+            _ => Err(SensorValidationError::InvalidUnit(value.to_string())),
+        }
+    }
+}
+
+/// Structure representing barometric pressure sensor data.
+///
+/// Holds pressure data collected from IoT devices.
+/// Validation is performed during instance creation.
+///
+/// # Fields
+///
+/// * `device_id` - Unique identifier for the device
+/// * `timestamp` - Measurement time (UTC)
+/// * `value` - Pressure value (300.0 to 1,100.0 hPa unless the validation policy
+///   sets another range, or the same range in `unit`)
+/// * `unit` - Unit of measurement
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::sensors::pressure::{PressureSensor, PressureUnit};
+///
+/// let sensor = PressureSensor::new(
+///     "device-001".to_string(),
+///     Utc::now(),
+///     1013.25,
+///     PressureUnit::Hectopascal,
+/// ).expect("Valid sensor data");
+///
+/// assert_eq!(sensor.device_id(), "device-001");
+/// assert_eq!(sensor.value(), 1013.25);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PressureSensor {
+    device_id: String,
+    timestamp: DateTime<Utc>,
+    value: f64,
+    unit: PressureUnit,
+}
+
+impl PressureSensor {
+    /// Default range of allowed values (300.0 to 1,100.0 hPa)
+    pub const DEFAULT_RANGE: ValueRange = ValueRange::new(300.0, 1_100.0);
+
+    /// Creates a new PressureSensor instance validated against the default
+    /// range.
+    ///
+    /// # Arguments
+    ///
+    /// * `device_id` - Unique identifier for the device (must not be empty)
+    /// * `timestamp` - Measurement time (must not be in the future)
+    /// * `value` - Pressure value in `unit` (300.0 to 1,100.0 hPa, i.e. 30.0 to
+    ///   110.0 kPa or 8.86 to 32.48 inHg)
+    /// * `unit` - Unit of measurement
+    ///
+    /// # Returns
+    ///
+    /// `Ok(PressureSensor)` on success, `Err(SensorValidationError)` on validation failure.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::EmptyDeviceId` - If device_id is empty
+    /// * `SensorValidationError::FutureTimestamp` - If timestamp is in the future
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn new(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: PressureUnit,
+    ) -> Result<Self, SensorValidationError> {
+        Self::with_policy(
+            device_id,
+            timestamp,
+            value,
+            unit,
+            &ValidationPolicy::default(),
+            &SystemClock,
+        )
+    }
+
+    /// Creates a new PressureSensor instance validated against the range the
+    /// policy sets for the device, in hectopascals.
+    ///
+    /// Timestamps are checked against `clock` and the timestamp rules of the
+    /// policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as `new`, and:
+    ///
+    /// * `SensorValidationError::StaleTimestamp` - If the policy sets a maximum
+    ///   age and the timestamp is older
+    pub fn with_policy(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: PressureUnit,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
+    ) -> Result<Self, SensorValidationError> {
+        if device_id.is_empty() {
+            return Err(SensorValidationError::EmptyDeviceId);
+        }
+
+        policy.timestamps.check(timestamp, clock.now())?;
+
+        Self::validate_value(value, unit, policy.limits_for(&device_id).pressure)?;
+
+        Ok(Self {
+            device_id,
+            timestamp,
+            value,
+            unit,
+        })
+    }
+
+    /// Checks that a value in the given unit lies within the given range.
+    ///
+    /// The range is defined in hectopascals, so the value is converted
+    /// before being compared and the range means the same in every unit.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range.
+    ///   `min` and `max` are expressed in `unit`.
+    pub fn validate_value(
+        value: f64,
+        unit: PressureUnit,
+        range: ValueRange,
+    ) -> Result<(), SensorValidationError> {
+        let hpa = unit.to_hpa(value);
+        if !range.contains_with_tolerance(hpa) {
+            let limits =
+                ValueRange::new(unit.from_hpa(range.min), unit.from_hpa(range.max)).rounded();
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: limits.min,
+                max: limits.max,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the device ID.
+    pub fn device_id(&self) -> &str {
+        &self.device_id
+    }
+
+    /// Returns the measurement timestamp.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Returns the pressure value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit of measurement.
+    pub fn unit(&self) -> PressureUnit {
+        self.unit
+    }
+
+    /// Returns the pressure in the given unit.
+    pub fn value_in(&self, unit: PressureUnit) -> f64 {
+        self.unit.convert(self.value, unit)
+    }
+
+    /// Returns the pressure in hectopascals.
+    pub fn to_hpa(&self) -> f64 {
+        self.value_in(PressureUnit::Hectopascal)
+    }
+
+    /// Returns the same reading expressed in another unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use domain::sensors::pressure::{PressureSensor, PressureUnit};
+    ///
+    /// let sensor = PressureSensor::new(
+    ///     "device-001".to_string(),
+    ///     Utc::now(),
+    ///     1013.25,
+    ///     PressureUnit::Hectopascal,
+    /// ).unwrap();
+    ///
+    /// let converted = sensor.convert_to(PressureUnit::Kilopascal);
+    /// assert_eq!(converted.value(), 101.325);
+    /// assert_eq!(converted.unit(), PressureUnit::Kilopascal);
+    /// ```
+    pub fn convert_to(&self, unit: PressureUnit) -> Self {
+        Self {
+            device_id: self.device_id.clone(),
+            timestamp: self.timestamp,
+            value: self.value_in(unit),
+            unit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod pressure_sensor_new {
+        use super::*;
+
+        #[test]
+        fn success_with_valid_data() {
+            let result = PressureSensor::new(
+                "device-001".to_string(),
+                Utc::now(),
+                1013.25,
+                PressureUnit::Hectopascal,
+            );
+
+            assert!(result.is_ok());
+            let sensor = result.unwrap();
+            assert_eq!(sensor.device_id(), "device-001");
+            assert_eq!(sensor.value(), 1013.25);
+            assert_eq!(sensor.unit(), PressureUnit::Hectopascal);
+        }
+
+        #[test]
+        fn fails_with_empty_device_id() {
+            let result = PressureSensor::new(
+                "".to_string(),
+                Utc::now(),
+                1013.25,
+                PressureUnit::Hectopascal,
+            );
+
+            assert_eq!(result, Err(SensorValidationError::EmptyDeviceId));
+        }
+
+        #[test]
+        fn fails_with_future_timestamp() {
+            let future = Utc::now() + chrono::Duration::hours(1);
+
+            let result = PressureSensor::new(
+                "device-001".to_string(),
+                future,
+                1013.25,
+                PressureUnit::Hectopascal,
+            );
+
+            assert_eq!(result, Err(SensorValidationError::FutureTimestamp));
+        }
+
+        #[test]
+        fn fails_with_value_below_min() {
+            let result = PressureSensor::new(
+                "device-001".to_string(),
+                Utc::now(),
+                250.0,
+                PressureUnit::Hectopascal,
+            );
+
+            assert!(matches!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange { .. })
+            ));
+        }
+
+        #[test]
+        fn success_with_boundary_values() {
+            for value in [300.0, 1_100.0] {
+                let result = PressureSensor::new(
+                    "device-001".to_string(),
+                    Utc::now(),
+                    value,
+                    PressureUnit::Hectopascal,
+                );
+
+                assert!(result.is_ok());
+            }
+        }
+
+        #[test]
+        fn success_with_boundary_in_pascal() {
+            // 変換誤差で境界値が範囲外にならないこと
+            let result = PressureSensor::new(
+                "device-001".to_string(),
+                Utc::now(),
+                110_000.0,
+                PressureUnit::Pascal,
+            );
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn fails_with_inhg_above_max() {
+            let result = PressureSensor::new(
+                "device-001".to_string(),
+                Utc::now(),
+                33.0,
+                PressureUnit::InchOfMercury,
+            );
+
+            assert_eq!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange {
+                    value: 33.0,
+                    min: 8.86,
+                    max: 32.48,
+                })
+            );
+        }
+    }
+
+    mod pressure_sensor_conversion {
+        use super::*;
+
+        fn sensor(value: f64, unit: PressureUnit) -> PressureSensor {
+            PressureSensor::new("device-001".to_string(), Utc::now(), value, unit).unwrap()
+        }
+
+        fn assert_close(actual: f64, expected: f64) {
+            assert!(
+                (actual - expected).abs() < 1e-6,
+                "expected {}, got {}",
+                expected,
+                actual
+            );
+        }
+
+        #[test]
+        fn converts_standard_atmosphere() {
+            let sensor = sensor(1013.25, PressureUnit::Hectopascal);
+
+            assert_close(sensor.value_in(PressureUnit::Kilopascal), 101.325);
+            assert_close(sensor.value_in(PressureUnit::Pascal), 101_325.0);
+            assert_close(sensor.value_in(PressureUnit::InchOfMercury), 29.921_255);
+            // 1 mmHg は 1 Torr よりわずかに大きい
+            assert_close(
+                sensor.value_in(PressureUnit::MillimeterOfMercury),
+                759.999_892,
+            );
+        }
+
+        #[test]
+        fn converts_mercury_units_to_hpa() {
+            assert_close(
+                sensor(29.92, PressureUnit::InchOfMercury).to_hpa(),
+                1_013.207_489,
+            );
+            assert_close(
+                sensor(760.0, PressureUnit::MillimeterOfMercury).to_hpa(),
+                1_013.250_144,
+            );
+        }
+
+        #[test]
+        fn convert_to_keeps_device_and_timestamp() {
+            let original = sensor(100_000.0, PressureUnit::Pascal);
+
+            let converted = original.convert_to(PressureUnit::Hectopascal);
+
+            assert_eq!(converted.device_id(), original.device_id());
+            assert_eq!(converted.timestamp(), original.timestamp());
+            assert_eq!(converted.unit(), PressureUnit::Hectopascal);
+            assert_close(converted.value(), 1000.0);
+        }
+    }
+
+    mod pressure_unit {
+        use super::*;
+
+        #[test]
+        fn as_str_returns_symbols() {
+            assert_eq!(PressureUnit::Hectopascal.as_str(), "hPa");
+            assert_eq!(PressureUnit::Kilopascal.as_str(), "kPa");
+            assert_eq!(PressureUnit::Pascal.as_str(), "Pa");
+            assert_eq!(PressureUnit::InchOfMercury.as_str(), "inHg");
+            assert_eq!(PressureUnit::MillimeterOfMercury.as_str(), "mmHg");
+        }
+
+        #[test]
+        fn try_from_is_case_insensitive() {
+            assert_eq!(PressureUnit::try_from("HPA"), Ok(PressureUnit::Hectopascal));
+            assert_eq!(
+                PressureUnit::try_from("inhg"),
+                Ok(PressureUnit::InchOfMercury)
+            );
+        }
+
+        #[test]
+        fn try_from_invalid_unit() {
+            assert_eq!(
+                PressureUnit::try_from("bar"),
+                Err(SensorValidationError::InvalidUnit("bar".to_string()))
+            );
+        }
+    }
+}
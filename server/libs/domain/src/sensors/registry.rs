@@ -0,0 +1,230 @@
+//! Sensor Kind Registry Module
+//!
+//! Describes the kinds of additional sensors a deployment knows about, so that
+//! entries of `SensorData::additional_sensors` are validated and normalized
+//! like the built-in measurements.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::sensors::error::SensorValidationError;
+use crate::sensors::policy::ValueRange;
+
+/// A named kind of additional sensor.
+///
+/// Values are stored in `unit`. Every other accepted unit maps to the factor
+/// converting a value in that unit to `unit`. Unit names are matched
+/// case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use domain::sensors::policy::ValueRange;
+/// use domain::sensors::registry::SensorKind;
+///
+/// let wind_speed = SensorKind::new("m/s", ValueRange::new(0.0, 100.0))
+///     .with_unit("cm/s", 0.01);
+///
+/// assert_eq!(wind_speed.to_canonical(250.0, "CM/S"), Ok(2.5));
+/// assert!(wind_speed.validate_value(20_000.0, "cm/s").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SensorKind {
+    pub unit: String,
+    pub range: ValueRange,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub units: HashMap<String, f64>,
+}
+
+impl SensorKind {
+    /// Creates a new SensorKind stored in `unit` and validated against
+    /// `range`, expressed in that unit.
+    pub fn new(unit: impl Into<String>, range: ValueRange) -> Self {
+        Self {
+            unit: unit.into(),
+            range,
+            units: HashMap::new(),
+        }
+    }
+
+    /// Accepts `unit`, converted to the canonical unit by multiplying with
+    /// `factor`.
+    pub fn with_unit(mut self, unit: impl Into<String>, factor: f64) -> Self {
+        self.units.insert(unit.into(), factor);
+        self
+    }
+
+    /// Returns the factor converting a value in `unit` to the canonical unit.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::InvalidUnit` - If the unit is not accepted
+    pub fn factor(&self, unit: &str) -> Result<f64, SensorValidationError> {
+        if unit.eq_ignore_ascii_case(&self.unit) {
+            return Ok(1.0);
+        }
+        self.units
+            .iter()
+            .find(|(name, _)| unit.eq_ignore_ascii_case(name))
+            .map(|(_, factor)| *factor)
+            .ok_or_else(|| SensorValidationError::InvalidUnit(unit.to_string()))
+    }
+
+    /// Converts a value in `unit` to the canonical unit.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::InvalidUnit` - If the unit is not accepted
+    pub fn to_canonical(&self, value: f64, unit: &str) -> Result<f64, SensorValidationError> {
+        Ok(value * self.factor(unit)?)
+    }
+
+    /// Checks that a value in `unit` lies within the range of the kind.
+    ///
+    /// An out-of-range error reports the limits in `unit`, like the value.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::InvalidUnit` - If the unit is not accepted
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn validate_value(&self, value: f64, unit: &str) -> Result<(), SensorValidationError> {
+        let factor = self.factor(unit)?;
+        let canonical = value * factor;
+        if !self.range.contains_with_tolerance(canonical) {
+            let limits =
+                ValueRange::new(self.range.min / factor, self.range.max / factor).rounded();
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: limits.min,
+                max: limits.max,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// What happens to additional sensors whose kind is not registered.
+///
+/// # Variants
+///
+/// * `PassThrough` - They are stored as received, without validation
+/// * `Reject` - The reading is rejected
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownKinds {
+    #[default]
+    PassThrough,
+    Reject,
+}
+
+/// The kinds of additional sensors, by name.
+///
+/// # Examples
+///
+/// ```
+/// use domain::sensors::registry::{SensorRegistry, UnknownKinds};
+///
+/// let registry: SensorRegistry = serde_json::from_str(r#"{
+///     "unknown": "reject",
+///     "kinds": {
+///         "wind_speed": {
+///             "unit": "m/s",
+///             "range": { "min": 0.0, "max": 100.0 },
+///             "units": { "km/h": 0.277778 }
+///         }
+///     }
+/// }"#).unwrap();
+///
+/// assert_eq!(registry.unknown, UnknownKinds::Reject);
+/// assert_eq!(registry.get("wind_speed").unwrap().unit, "m/s");
+/// assert!(registry.get("lux").is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SensorRegistry {
+    pub unknown: UnknownKinds,
+    pub kinds: HashMap<String, SensorKind>,
+}
+
+impl SensorRegistry {
+    /// Creates an empty registry passing unknown kinds through.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `kind` under `name`, replacing any kind of the same name.
+    pub fn with_kind(mut self, name: impl Into<String>, kind: SensorKind) -> Self {
+        self.kinds.insert(name.into(), kind);
+        self
+    }
+
+    /// Sets what happens to sensors of unregistered kinds.
+    pub fn with_unknown(mut self, unknown: UnknownKinds) -> Self {
+        self.unknown = unknown;
+        self
+    }
+
+    /// Returns the kind registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&SensorKind> {
+        self.kinds.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MPH: f64 = 0.447_04;
+
+    fn wind_speed() -> SensorKind {
+        SensorKind::new("m/s", ValueRange::new(0.0, 100.0)).with_unit("mph", MPH)
+    }
+
+    mod sensor_kind {
+        use super::*;
+
+        #[test]
+        fn converts_accepted_units_to_canonical() {
+            assert_eq!(wind_speed().to_canonical(3.5, "m/s"), Ok(3.5));
+            assert_eq!(wind_speed().to_canonical(10.0, "MPH"), Ok(10.0 * MPH));
+        }
+
+        #[test]
+        fn rejects_unknown_unit() {
+            assert_eq!(
+                wind_speed().to_canonical(3.5, "knots"),
+                Err(SensorValidationError::InvalidUnit("knots".to_string()))
+            );
+        }
+
+        #[test]
+        fn validates_in_canonical_unit() {
+            assert_eq!(wind_speed().validate_value(100.0, "m/s"), Ok(()));
+            // 223 mph ≒ 99.69 m/s
+            assert_eq!(wind_speed().validate_value(223.0, "mph"), Ok(()));
+            assert_eq!(
+                wind_speed().validate_value(224.0, "mph"),
+                Err(SensorValidationError::ValueOutOfRange {
+                    value: 224.0,
+                    min: 0.0,
+                    max: 223.69,
+                })
+            );
+        }
+    }
+
+    mod sensor_registry {
+        use super::*;
+
+        #[test]
+        fn passes_unknown_kinds_through_by_default() {
+            let registry = SensorRegistry::new().with_kind("wind_speed", wind_speed());
+
+            assert_eq!(registry.unknown, UnknownKinds::PassThrough);
+            assert!(registry.get("wind_speed").is_some());
+            assert!(registry.get("lux").is_none());
+        }
+    }
+}
@@ -4,7 +4,11 @@
 
 use chrono::{DateTime, Utc};
 
-use crate::sensors::{co2::CO2Sensor, humidity::HumiditySensor, temperature::TemperatureSensor};
+use crate::sensors::{
+    co2::CO2Sensor, humidity::HumiditySensor, illuminance::IlluminanceSensor,
+    particulate::ParticulateSensor, pressure::PressureSensor, sound_level::SoundLevelSensor,
+    temperature::TemperatureSensor, voc::VocSensor,
+};
 
 /// A trait representing common behavior for all sensor types.
 ///
@@ -88,10 +92,114 @@ impl Sensor for CO2Sensor {
     }
 }
 
+/// Implementation of the Sensor trait for PressureSensor.
+impl Sensor for PressureSensor {
+    fn device_id(&self) -> &str {
+        self.device_id()
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp()
+    }
+
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn unit(&self) -> &str {
+        self.unit().as_str()
+    }
+}
+
+/// Implementation of the Sensor trait for ParticulateSensor.
+impl Sensor for ParticulateSensor {
+    fn device_id(&self) -> &str {
+        self.device_id()
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp()
+    }
+
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn unit(&self) -> &str {
+        self.unit().as_str()
+    }
+}
+
+/// Implementation of the Sensor trait for VocSensor.
+impl Sensor for VocSensor {
+    fn device_id(&self) -> &str {
+        self.device_id()
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp()
+    }
+
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn unit(&self) -> &str {
+        self.unit().as_str()
+    }
+}
+
+/// Implementation of the Sensor trait for IlluminanceSensor.
+impl Sensor for IlluminanceSensor {
+    fn device_id(&self) -> &str {
+        self.device_id()
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp()
+    }
+
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn unit(&self) -> &str {
+        self.unit().as_str()
+    }
+}
+
+/// Implementation of the Sensor trait for SoundLevelSensor.
+impl Sensor for SoundLevelSensor {
+    fn device_id(&self) -> &str {
+        self.device_id()
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp()
+    }
+
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn unit(&self) -> &str {
+        self.unit().as_str()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::sensors::{co2::CO2Unit, humidity::HumidityUnit, temperature::TemperatureUnit};
+    use crate::sensors::{
+        co2::CO2Unit,
+        humidity::HumidityUnit,
+        illuminance::IlluminanceUnit,
+        particulate::{ParticleSize, ParticulateUnit},
+        pressure::PressureUnit,
+        sound_level::SoundLevelUnit,
+        temperature::TemperatureUnit,
+        voc::VocUnit,
+    };
 
     mod sensor_trait_for_temperature {
         use super::*;
@@ -206,4 +314,144 @@ mod tests {
             assert_eq!(Sensor::unit(&sensor), "ppm");
         }
     }
+
+    mod sensor_trait_for_pressure {
+        use super::*;
+
+        fn sensor() -> PressureSensor {
+            PressureSensor::new(
+                "pres-001".to_string(),
+                Utc::now(),
+                29.92,
+                PressureUnit::InchOfMercury,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn returns_device_id() {
+            assert_eq!(Sensor::device_id(&sensor()), "pres-001");
+        }
+
+        #[test]
+        fn returns_value() {
+            assert_eq!(Sensor::value(&sensor()), 29.92);
+        }
+
+        #[test]
+        fn returns_unit_as_string() {
+            assert_eq!(Sensor::unit(&sensor()), "inHg");
+        }
+    }
+
+    mod sensor_trait_for_particulate {
+        use super::*;
+
+        fn sensor() -> ParticulateSensor {
+            ParticulateSensor::new(
+                "pm-001".to_string(),
+                Utc::now(),
+                ParticleSize::Pm2_5,
+                12.0,
+                ParticulateUnit::MicrogramsPerCubicMeter,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn returns_device_id() {
+            assert_eq!(Sensor::device_id(&sensor()), "pm-001");
+        }
+
+        #[test]
+        fn returns_value() {
+            assert_eq!(Sensor::value(&sensor()), 12.0);
+        }
+
+        #[test]
+        fn returns_unit_as_string() {
+            assert_eq!(Sensor::unit(&sensor()), "µg/m³");
+        }
+    }
+
+    mod sensor_trait_for_voc {
+        use super::*;
+
+        fn sensor() -> VocSensor {
+            VocSensor::new("voc-001".to_string(), Utc::now(), 250.0, VocUnit::Ppb).unwrap()
+        }
+
+        #[test]
+        fn returns_device_id() {
+            assert_eq!(Sensor::device_id(&sensor()), "voc-001");
+        }
+
+        #[test]
+        fn returns_value() {
+            assert_eq!(Sensor::value(&sensor()), 250.0);
+        }
+
+        #[test]
+        fn returns_unit_as_string() {
+            assert_eq!(Sensor::unit(&sensor()), "ppb");
+        }
+    }
+
+    mod sensor_trait_for_illuminance {
+        use super::*;
+
+        fn sensor() -> IlluminanceSensor {
+            IlluminanceSensor::new(
+                "lux-001".to_string(),
+                Utc::now(),
+                500.0,
+                IlluminanceUnit::Lux,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn returns_device_id() {
+            assert_eq!(Sensor::device_id(&sensor()), "lux-001");
+        }
+
+        #[test]
+        fn returns_value() {
+            assert_eq!(Sensor::value(&sensor()), 500.0);
+        }
+
+        #[test]
+        fn returns_unit_as_string() {
+            assert_eq!(Sensor::unit(&sensor()), "lux");
+        }
+    }
+
+    mod sensor_trait_for_sound_level {
+        use super::*;
+
+        fn sensor() -> SoundLevelSensor {
+            SoundLevelSensor::new(
+                "snd-001".to_string(),
+                Utc::now(),
+                42.5,
+                SoundLevelUnit::DecibelA,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn returns_device_id() {
+            assert_eq!(Sensor::device_id(&sensor()), "snd-001");
+        }
+
+        #[test]
+        fn returns_value() {
+            assert_eq!(Sensor::value(&sensor()), 42.5);
+        }
+
+        #[test]
+        fn returns_unit_as_string() {
+            assert_eq!(Sensor::unit(&sensor()), "dBA");
+        }
+    }
 }
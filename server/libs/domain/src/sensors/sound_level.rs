@@ -0,0 +1,300 @@
+//! Sound Level Sensor Module
+//!
+//! Provides structures and validation for A-weighted sound pressure level
+//! sensor data.
+
+use chrono::{DateTime, Utc};
+
+use crate::clock::{Clock, SystemClock};
+use crate::sensors::error::SensorValidationError;
+use crate::sensors::policy::{ValidationPolicy, ValueRange};
+
+/// Enumeration representing the unit of sound level measurement.
+///
+/// # Variants
+///
+/// * `DecibelA` - A-weighted decibels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundLevelUnit {
+    DecibelA,
+}
+
+impl SoundLevelUnit {
+    /// The unit measurements are stored in.
+    pub const CANONICAL: SoundLevelUnit = SoundLevelUnit::DecibelA;
+
+    /// Returns the unit as a string slice.
+    ///
+    /// # Returns
+    ///
+    /// A static string slice representing the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::sensors::sound_level::SoundLevelUnit;
+    ///
+    /// let unit = SoundLevelUnit::DecibelA;
+    /// assert_eq!(unit.as_str(), "dBA");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SoundLevelUnit::DecibelA => "dBA",
+        }
+    }
+}
+
+impl TryFrom<&str> for SoundLevelUnit {
+    type Error = SensorValidationError;
+
+    /// Attempts to convert a string to SoundLevelUnit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The string to convert (case-insensitive, accepts "dBA" and
+    ///   "dB(A)")
+    ///
+    /// # Returns
+    ///
+    /// `Ok(SoundLevelUnit)` on success, `Err(SensorValidationError::InvalidUnit)` on failure.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "dba" | "db(a)" => Ok(SoundLevelUnit::DecibelA),
+            _ => Err(SensorValidationError::InvalidUnit(value.to_string())),
+        }
+    }
+}
+
+/// Structure representing sound level sensor data.
+///
+/// Holds A-weighted sound level data collected from IoT devices.
+/// Validation is performed during instance creation.
+///
+/// # Fields
+///
+/// * `device_id` - Unique identifier for the device
+/// * `timestamp` - Measurement time (UTC)
+/// * `value` - Sound level (0.0 to 140.0 dBA unless the validation policy
+///   sets another range)
+/// * `unit` - Unit of measurement
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::sensors::sound_level::{SoundLevelSensor, SoundLevelUnit};
+///
+/// let sensor = SoundLevelSensor::new(
+///     "device-001".to_string(),
+///     Utc::now(),
+///     42.5,
+///     SoundLevelUnit::DecibelA,
+/// ).expect("Valid sensor data");
+///
+/// assert_eq!(sensor.device_id(), "device-001");
+/// assert_eq!(sensor.value(), 42.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoundLevelSensor {
+    device_id: String,
+    timestamp: DateTime<Utc>,
+    value: f64,
+    unit: SoundLevelUnit,
+}
+
+impl SoundLevelSensor {
+    /// Default range of allowed values (0.0 to 140.0 dBA)
+    pub const DEFAULT_RANGE: ValueRange = ValueRange::new(0.0, 140.0);
+
+    /// Creates a new SoundLevelSensor instance validated against the default
+    /// range.
+    ///
+    /// # Arguments
+    ///
+    /// * `device_id` - Unique identifier for the device (must not be empty)
+    /// * `timestamp` - Measurement time (must not be in the future)
+    /// * `value` - Sound level (0.0 to 140.0 dBA unless the validation policy
+    ///   sets another range)
+    /// * `unit` - Unit of measurement
+    ///
+    /// # Returns
+    ///
+    /// `Ok(SoundLevelSensor)` on success, `Err(SensorValidationError)` on validation failure.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::EmptyDeviceId` - If device_id is empty
+    /// * `SensorValidationError::FutureTimestamp` - If timestamp is in the future
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn new(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: SoundLevelUnit,
+    ) -> Result<Self, SensorValidationError> {
+        Self::with_policy(
+            device_id,
+            timestamp,
+            value,
+            unit,
+            &ValidationPolicy::default(),
+            &SystemClock,
+        )
+    }
+
+    /// Creates a new SoundLevelSensor instance validated against the range
+    /// the policy sets for the device.
+    ///
+    /// Timestamps are checked against `clock` and the timestamp rules of the
+    /// policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as `new`, and:
+    ///
+    /// * `SensorValidationError::StaleTimestamp` - If the policy sets a maximum
+    ///   age and the timestamp is older
+    pub fn with_policy(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: SoundLevelUnit,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
+    ) -> Result<Self, SensorValidationError> {
+        if device_id.is_empty() {
+            return Err(SensorValidationError::EmptyDeviceId);
+        }
+
+        policy.timestamps.check(timestamp, clock.now())?;
+
+        Self::validate_value(value, policy.limits_for(&device_id).sound_level)?;
+
+        Ok(Self {
+            device_id,
+            timestamp,
+            value,
+            unit,
+        })
+    }
+
+    /// Checks that a value lies within the given range.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn validate_value(value: f64, range: ValueRange) -> Result<(), SensorValidationError> {
+        if !range.contains(value) {
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: range.min,
+                max: range.max,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the device ID.
+    pub fn device_id(&self) -> &str {
+        &self.device_id
+    }
+
+    /// Returns the measurement timestamp.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Returns the sound level value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit of measurement.
+    pub fn unit(&self) -> SoundLevelUnit {
+        self.unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sensor(value: f64) -> Result<SoundLevelSensor, SensorValidationError> {
+        SoundLevelSensor::new(
+            "device-001".to_string(),
+            Utc::now(),
+            value,
+            SoundLevelUnit::DecibelA,
+        )
+    }
+
+    mod sound_level_sensor_new {
+        use super::*;
+
+        #[test]
+        fn success_with_valid_data() {
+            let result = sensor(42.5);
+
+            assert!(result.is_ok());
+            let sensor = result.unwrap();
+            assert_eq!(sensor.device_id(), "device-001");
+            assert_eq!(sensor.value(), 42.5);
+            assert_eq!(sensor.unit(), SoundLevelUnit::DecibelA);
+        }
+
+        #[test]
+        fn fails_with_future_timestamp() {
+            let future = Utc::now() + chrono::Duration::hours(1);
+
+            let result = SoundLevelSensor::new(
+                "device-001".to_string(),
+                future,
+                42.5,
+                SoundLevelUnit::DecibelA,
+            );
+
+            assert_eq!(result, Err(SensorValidationError::FutureTimestamp));
+        }
+
+        #[test]
+        fn fails_with_value_out_of_range() {
+            for value in [-3.5, 140.5] {
+                assert!(matches!(
+                    sensor(value),
+                    Err(SensorValidationError::ValueOutOfRange { .. })
+                ));
+            }
+        }
+
+        #[test]
+        fn success_with_boundary_values() {
+            assert!(sensor(0.0).is_ok());
+            assert!(sensor(140.0).is_ok());
+        }
+    }
+
+    mod sound_level_unit {
+        use super::*;
+
+        #[test]
+        fn as_str_returns_dba() {
+            assert_eq!(SoundLevelUnit::DecibelA.as_str(), "dBA");
+        }
+
+        #[test]
+        fn try_from_accepts_common_spellings() {
+            for unit in ["dBA", "dba", "DBA", "dB(A)"] {
+                assert_eq!(SoundLevelUnit::try_from(unit), Ok(SoundLevelUnit::DecibelA));
+            }
+        }
+
+        #[test]
+        fn try_from_invalid_unit() {
+            // 周波数重み付けのない dB は受け付けない
+            let result = SoundLevelUnit::try_from("dB");
+
+            assert!(matches!(result, Err(SensorValidationError::InvalidUnit(_))));
+        }
+    }
+}
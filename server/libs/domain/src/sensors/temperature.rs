@@ -4,12 +4,12 @@
 
 use chrono::{DateTime, Utc};
 
+use crate::clock::{Clock, SystemClock};
 use crate::sensors::error::SensorValidationError;
+use crate::sensors::policy::{ValidationPolicy, ValueRange};
 
-/// Minimum allowed value for temperature sensor
-const MIN_VALUE: f64 = -50.0;
-/// Maximum allowed value for temperature sensor
-const MAX_VALUE: f64 = 150.0;
+/// Absolute zero (°C)
+const ABSOLUTE_ZERO: f64 = -273.15;
 
 /// Enumeration representing the unit of temperature measurement.
 ///
@@ -17,13 +17,18 @@ const MAX_VALUE: f64 = 150.0;
 ///
 /// * `Celsius` - Degrees Celsius
 /// * `Fahrenheit` - Degrees Fahrenheit
+/// * `Kelvin` - Kelvin
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TemperatureUnit {
     Celsius,
     Fahrenheit,
+    Kelvin,
 }
 
 impl TemperatureUnit {
+    /// The unit validation limits are defined in and measurements are stored in.
+    pub const CANONICAL: TemperatureUnit = TemperatureUnit::Celsius;
+
     /// Returns the unit as a string slice.
     ///
     /// # Returns
@@ -42,8 +47,43 @@ impl TemperatureUnit {
         match self {
             TemperatureUnit::Celsius => "Celsius",
             TemperatureUnit::Fahrenheit => "Fahrenheit",
+            TemperatureUnit::Kelvin => "Kelvin",
+        }
+    }
+
+    /// Converts a value in this unit to degrees Celsius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::sensors::temperature::TemperatureUnit;
+    ///
+    /// assert_eq!(TemperatureUnit::Fahrenheit.to_celsius(212.0), 100.0);
+    /// ```
+    pub fn to_celsius(&self, value: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => value,
+            TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            TemperatureUnit::Kelvin => value + ABSOLUTE_ZERO,
+        }
+    }
+
+    /// Converts a value in degrees Celsius to this unit.
+    pub fn from_celsius(&self, value: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => value,
+            TemperatureUnit::Fahrenheit => value * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Kelvin => value - ABSOLUTE_ZERO,
         }
     }
+
+    /// Converts a value in this unit to another unit.
+    pub fn convert(&self, value: f64, to: TemperatureUnit) -> f64 {
+        if *self == to {
+            return value;
+        }
+        to.from_celsius(self.to_celsius(value))
+    }
 }
 
 impl TryFrom<&str> for TemperatureUnit {
@@ -53,7 +93,8 @@ impl TryFrom<&str> for TemperatureUnit {
     ///
     /// # Arguments
     ///
-    /// * `value` - The string to convert (case-insensitive, accepts "celsius"/"c" or "fahrenheit"/"f")
+    /// * `value` - The string to convert (case-insensitive, accepts "celsius"/"c",
+    ///   "fahrenheit"/"f" or "kelvin"/"k")
     ///
     /// # Returns
     ///
@@ -62,6 +103,7 @@ impl TryFrom<&str> for TemperatureUnit {
         match value.to_lowercase().as_str() {
             "celsius" | "c" => Ok(TemperatureUnit::Celsius),
             "fahrenheit" | "f" => Ok(TemperatureUnit::Fahrenheit),
+            "kelvin" | "k" => Ok(TemperatureUnit::Kelvin),
             _ => Err(SensorValidationError::InvalidUnit(value.to_string())),
         }
     }
@@ -76,8 +118,9 @@ impl TryFrom<&str> for TemperatureUnit {
 ///
 /// * `device_id` - Unique identifier for the device
 /// * `timestamp` - Measurement time (UTC)
-/// * `value` - Temperature value (-50.0 to 150.0)
-/// * `unit` - Unit of measurement (Celsius or Fahrenheit)
+/// * `value` - Temperature value (-50.0 to 150.0 °C unless the validation policy
+///   sets another range, or the same range in `unit`)
+/// * `unit` - Unit of measurement (Celsius, Fahrenheit or Kelvin)
 ///
 /// # Examples
 ///
@@ -104,13 +147,18 @@ pub struct TemperatureSensor {
 }
 
 impl TemperatureSensor {
-    /// Creates a new TemperatureSensor instance.
+    /// Default range of allowed values (-50.0 to 150.0 °C)
+    pub const DEFAULT_RANGE: ValueRange = ValueRange::new(-50.0, 150.0);
+
+    /// Creates a new TemperatureSensor instance validated against the default
+    /// range.
     ///
     /// # Arguments
     ///
     /// * `device_id` - Unique identifier for the device (must not be empty)
     /// * `timestamp` - Measurement time (must not be in the future)
-    /// * `value` - Temperature value (-50.0 to 150.0)
+    /// * `value` - Temperature value in `unit` (-50.0 to 150.0 °C, i.e. -58.0 to 302.0 °F
+    ///   or 223.15 to 423.15 K)
     /// * `unit` - Unit of measurement
     ///
     /// # Returns
@@ -127,22 +175,44 @@ impl TemperatureSensor {
         timestamp: DateTime<Utc>,
         value: f64,
         unit: TemperatureUnit,
+    ) -> Result<Self, SensorValidationError> {
+        Self::with_policy(
+            device_id,
+            timestamp,
+            value,
+            unit,
+            &ValidationPolicy::default(),
+            &SystemClock,
+        )
+    }
+
+    /// Creates a new TemperatureSensor instance validated against the range
+    /// the policy sets for the device, in degrees Celsius.
+    ///
+    /// Timestamps are checked against `clock` and the timestamp rules of the
+    /// policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as `new`, and:
+    ///
+    /// * `SensorValidationError::StaleTimestamp` - If the policy sets a maximum
+    ///   age and the timestamp is older
+    pub fn with_policy(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: TemperatureUnit,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
     ) -> Result<Self, SensorValidationError> {
         if device_id.is_empty() {
             return Err(SensorValidationError::EmptyDeviceId);
         }
 
-        if timestamp > Utc::now() {
-            return Err(SensorValidationError::FutureTimestamp);
-        }
+        policy.timestamps.check(timestamp, clock.now())?;
 
-        if !(MIN_VALUE..=MAX_VALUE).contains(&value) {
-            return Err(SensorValidationError::ValueOutOfRange {
-                value,
-                min: MIN_VALUE,
-                max: MAX_VALUE,
-            });
-        }
+        Self::validate_value(value, unit, policy.limits_for(&device_id).temperature)?;
 
         Ok(Self {
             device_id,
@@ -152,6 +222,35 @@ impl TemperatureSensor {
         })
     }
 
+    /// Checks that a value in the given unit lies within the given range.
+    ///
+    /// The range is defined in degrees Celsius, so the value is converted
+    /// before being compared and the range means the same in every unit.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range.
+    ///   `min` and `max` are expressed in `unit`.
+    pub fn validate_value(
+        value: f64,
+        unit: TemperatureUnit,
+        range: ValueRange,
+    ) -> Result<(), SensorValidationError> {
+        let celsius = unit.to_celsius(value);
+        if !range.contains_with_tolerance(celsius) {
+            let limits =
+                ValueRange::new(unit.from_celsius(range.min), unit.from_celsius(range.max))
+                    .rounded();
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: limits.min,
+                max: limits.max,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Returns the device ID.
     pub fn device_id(&self) -> &str {
         &self.device_id
@@ -171,6 +270,54 @@ impl TemperatureSensor {
     pub fn unit(&self) -> TemperatureUnit {
         self.unit
     }
+
+    /// Returns the temperature in the given unit.
+    pub fn value_in(&self, unit: TemperatureUnit) -> f64 {
+        self.unit.convert(self.value, unit)
+    }
+
+    /// Returns the temperature in degrees Celsius.
+    pub fn to_celsius(&self) -> f64 {
+        self.value_in(TemperatureUnit::Celsius)
+    }
+
+    /// Returns the temperature in degrees Fahrenheit.
+    pub fn to_fahrenheit(&self) -> f64 {
+        self.value_in(TemperatureUnit::Fahrenheit)
+    }
+
+    /// Returns the temperature in Kelvin.
+    pub fn to_kelvin(&self) -> f64 {
+        self.value_in(TemperatureUnit::Kelvin)
+    }
+
+    /// Returns the same reading expressed in another unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use domain::sensors::temperature::{TemperatureSensor, TemperatureUnit};
+    ///
+    /// let sensor = TemperatureSensor::new(
+    ///     "device-001".to_string(),
+    ///     Utc::now(),
+    ///     100.0,
+    ///     TemperatureUnit::Celsius,
+    /// ).unwrap();
+    ///
+    /// let converted = sensor.convert_to(TemperatureUnit::Fahrenheit);
+    /// assert_eq!(converted.value(), 212.0);
+    /// assert_eq!(converted.unit(), TemperatureUnit::Fahrenheit);
+    /// ```
+    pub fn convert_to(&self, unit: TemperatureUnit) -> Self {
+        Self {
+            device_id: self.device_id.clone(),
+            timestamp: self.timestamp,
+            value: self.value_in(unit),
+            unit,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -272,6 +419,73 @@ mod tests {
             assert!(result.is_ok());
         }
 
+        #[test]
+        fn success_with_fahrenheit_above_celsius_limit() {
+            // 200°F は約 93.3°C なので範囲内
+            let result = TemperatureSensor::new(
+                "device-001".to_string(),
+                Utc::now(),
+                200.0,
+                TemperatureUnit::Fahrenheit,
+            );
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn fails_with_fahrenheit_above_max() {
+            let result = TemperatureSensor::new(
+                "device-001".to_string(),
+                Utc::now(),
+                310.0,
+                TemperatureUnit::Fahrenheit,
+            );
+
+            assert_eq!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange {
+                    value: 310.0,
+                    min: -58.0,
+                    max: 302.0,
+                })
+            );
+        }
+
+        #[test]
+        fn fails_with_kelvin_below_min() {
+            let result = TemperatureSensor::new(
+                "device-001".to_string(),
+                Utc::now(),
+                25.0,
+                TemperatureUnit::Kelvin,
+            );
+
+            assert_eq!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange {
+                    value: 25.0,
+                    min: 223.15,
+                    max: 423.15,
+                })
+            );
+        }
+
+        #[test]
+        fn success_with_boundary_values_in_every_unit() {
+            for (min, max, unit) in [
+                (-50.0, 150.0, TemperatureUnit::Celsius),
+                (-58.0, 302.0, TemperatureUnit::Fahrenheit),
+                (223.15, 423.15, TemperatureUnit::Kelvin),
+            ] {
+                for value in [min, max] {
+                    let result =
+                        TemperatureSensor::new("device-001".to_string(), Utc::now(), value, unit);
+
+                    assert!(result.is_ok(), "{} {:?} should be valid", value, unit);
+                }
+            }
+        }
+
         #[test]
         fn success_with_fahrenheit_unit() {
             let result = TemperatureSensor::new(
@@ -287,9 +501,195 @@ mod tests {
         }
     }
 
+    mod temperature_sensor_with_policy {
+        use super::*;
+        use crate::sensors::policy::{DeviceLimits, LimitOverrides};
+
+        fn freezer_policy() -> ValidationPolicy {
+            let mut policy = ValidationPolicy::default();
+            policy.devices.insert(
+                "freezer-01".to_string(),
+                DeviceLimits {
+                    limits: LimitOverrides {
+                        temperature: Some(ValueRange::new(-80.0, 0.0)),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            );
+            policy
+        }
+
+        #[test]
+        fn success_with_value_in_device_range() {
+            let result = TemperatureSensor::with_policy(
+                "freezer-01".to_string(),
+                Utc::now(),
+                -70.0,
+                TemperatureUnit::Celsius,
+                &freezer_policy(),
+                &SystemClock,
+            );
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn fails_with_value_outside_device_range() {
+            let result = TemperatureSensor::with_policy(
+                "freezer-01".to_string(),
+                Utc::now(),
+                5.0,
+                TemperatureUnit::Celsius,
+                &freezer_policy(),
+                &SystemClock,
+            );
+
+            assert_eq!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange {
+                    value: 5.0,
+                    min: -80.0,
+                    max: 0.0,
+                })
+            );
+        }
+
+        #[test]
+        fn checks_timestamp_against_given_clock() {
+            // 実時刻より先の時刻でも、固定した時計に対しては過去になる
+            let later = Utc::now() + chrono::Duration::days(1);
+            let clock = crate::clock::FixedClock::new(later);
+
+            let result = TemperatureSensor::with_policy(
+                "device-001".to_string(),
+                later - chrono::Duration::hours(1),
+                25.0,
+                TemperatureUnit::Celsius,
+                &ValidationPolicy::default(),
+                &clock,
+            );
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn fails_with_timestamp_older_than_max_age() {
+            let mut policy = ValidationPolicy::default();
+            policy.timestamps.max_age_days = Some(1);
+
+            let result = TemperatureSensor::with_policy(
+                "device-001".to_string(),
+                Utc::now() - chrono::Duration::days(2),
+                25.0,
+                TemperatureUnit::Celsius,
+                &policy,
+                &SystemClock,
+            );
+
+            assert_eq!(
+                result,
+                Err(SensorValidationError::StaleTimestamp { max_age_days: 1 })
+            );
+        }
+
+        #[test]
+        fn other_devices_use_default_range() {
+            // 上書きのないデバイスは既定の範囲で検証する
+            let result = TemperatureSensor::with_policy(
+                "device-001".to_string(),
+                Utc::now(),
+                -70.0,
+                TemperatureUnit::Celsius,
+                &freezer_policy(),
+                &SystemClock,
+            );
+
+            assert!(matches!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange { .. })
+            ));
+        }
+    }
+
+    mod temperature_sensor_conversion {
+        use super::*;
+
+        fn sensor(value: f64, unit: TemperatureUnit) -> TemperatureSensor {
+            TemperatureSensor::new("device-001".to_string(), Utc::now(), value, unit).unwrap()
+        }
+
+        fn assert_close(actual: f64, expected: f64) {
+            assert!(
+                (actual - expected).abs() < 1e-9,
+                "expected {}, got {}",
+                expected,
+                actual
+            );
+        }
+
+        #[test]
+        fn converts_celsius() {
+            let sensor = sensor(25.0, TemperatureUnit::Celsius);
+
+            assert_close(sensor.to_celsius(), 25.0);
+            assert_close(sensor.to_fahrenheit(), 77.0);
+            assert_close(sensor.to_kelvin(), 298.15);
+        }
+
+        #[test]
+        fn converts_fahrenheit() {
+            let sensor = sensor(-40.0, TemperatureUnit::Fahrenheit);
+
+            assert_close(sensor.to_celsius(), -40.0);
+            assert_close(sensor.to_kelvin(), 233.15);
+        }
+
+        #[test]
+        fn converts_kelvin() {
+            let sensor = sensor(373.15, TemperatureUnit::Kelvin);
+
+            assert_close(sensor.to_celsius(), 100.0);
+            assert_close(sensor.to_fahrenheit(), 212.0);
+        }
+
+        #[test]
+        fn convert_to_keeps_device_and_timestamp() {
+            let original = sensor(300.0, TemperatureUnit::Kelvin);
+
+            let converted = original.convert_to(TemperatureUnit::Celsius);
+
+            assert_eq!(converted.device_id(), original.device_id());
+            assert_eq!(converted.timestamp(), original.timestamp());
+            assert_eq!(converted.unit(), TemperatureUnit::Celsius);
+            assert_close(converted.value(), 26.85);
+        }
+
+        #[test]
+        fn convert_to_same_unit_is_identity() {
+            let original = sensor(21.3, TemperatureUnit::Fahrenheit);
+
+            assert_eq!(original.convert_to(TemperatureUnit::Fahrenheit), original);
+        }
+    }
+
     mod temperature_unit {
         use super::*;
 
+        #[test]
+        fn as_str_returns_kelvin() {
+            assert_eq!(TemperatureUnit::Kelvin.as_str(), "Kelvin");
+        }
+
+        #[test]
+        fn try_from_kelvin() {
+            assert_eq!(
+                TemperatureUnit::try_from("kelvin"),
+                Ok(TemperatureUnit::Kelvin)
+            );
+            assert_eq!(TemperatureUnit::try_from("K"), Ok(TemperatureUnit::Kelvin));
+        }
+
         #[test]
         fn as_str_returns_celsius() {
             assert_eq!(TemperatureUnit::Celsius.as_str(), "Celsius");
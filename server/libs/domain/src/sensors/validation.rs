@@ -0,0 +1,924 @@
+//! Sensor Data Validation Module
+//!
+//! Converts free-form `SensorData` into the typed, validated sensor types and
+//! reports every problem found along the way.
+
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::clock::{Clock, FixedClock, SystemClock};
+use crate::entities::{SensorData, SensorMeasurement};
+use crate::sensors::{
+    co2::{CO2Sensor, CO2Unit},
+    error::SensorValidationError,
+    humidity::{HumiditySensor, HumidityUnit},
+    illuminance::{IlluminanceSensor, IlluminanceUnit},
+    particulate::{ParticleSize, ParticulateSensor, ParticulateUnit},
+    policy::ValidationPolicy,
+    pressure::{PressureSensor, PressureUnit},
+    registry::{SensorRegistry, UnknownKinds},
+    sound_level::{SoundLevelSensor, SoundLevelUnit},
+    temperature::{TemperatureSensor, TemperatureUnit},
+    voc::{VocSensor, VocUnit},
+};
+
+/// A validation error together with the path of the `SensorData` field that
+/// caused it, such as `device_id` or `temperature.value`.
+///
+/// Serializes as the path, a human-readable `message` and a machine-readable
+/// `code` with the details of the error:
+///
+/// ```json
+/// { "path": "co2.value", "message": "value 60000 is out of range [0, 50000]",
+///   "code": "value_out_of_range", "value": 60000.0, "min": 0.0, "max": 50000.0 }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub path: String,
+    pub error: SensorValidationError,
+}
+
+impl FieldError {
+    /// Creates a new FieldError for the given field path.
+    pub fn new(path: impl Into<String>, error: SensorValidationError) -> Self {
+        Self {
+            path: path.into(),
+            error,
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.error)
+    }
+}
+
+/// Serializable mirror of `SensorValidationError`, tagged by `code`.
+#[derive(Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+enum ErrorDetail<'a> {
+    EmptyDeviceId,
+    FutureTimestamp,
+    StaleTimestamp { max_age_days: u32 },
+    ValueOutOfRange { value: f64, min: f64, max: f64 },
+    InvalidUnit { unit: &'a str },
+    UnknownKind { kind: &'a str },
+}
+
+impl<'a> From<&'a SensorValidationError> for ErrorDetail<'a> {
+    fn from(error: &'a SensorValidationError) -> Self {
+        match error {
+            SensorValidationError::EmptyDeviceId => ErrorDetail::EmptyDeviceId,
+            SensorValidationError::FutureTimestamp => ErrorDetail::FutureTimestamp,
+            SensorValidationError::StaleTimestamp { max_age_days } => ErrorDetail::StaleTimestamp {
+                max_age_days: *max_age_days,
+            },
+            SensorValidationError::ValueOutOfRange { value, min, max } => {
+                ErrorDetail::ValueOutOfRange {
+                    value: *value,
+                    min: *min,
+                    max: *max,
+                }
+            }
+            SensorValidationError::InvalidUnit(unit) => ErrorDetail::InvalidUnit { unit },
+            SensorValidationError::UnknownKind(kind) => ErrorDetail::UnknownKind { kind },
+        }
+    }
+}
+
+impl Serialize for FieldError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Repr<'a> {
+            path: &'a str,
+            message: String,
+            #[serde(flatten)]
+            detail: ErrorDetail<'a>,
+        }
+
+        Repr {
+            path: &self.path,
+            message: self.error.to_string(),
+            detail: ErrorDetail::from(&self.error),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Every validation error found in a `SensorData`, in field order.
+///
+/// Serializes as `{ "errors": [...] }`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::entities::SensorData;
+/// use domain::sensors::validation::ValidationReport;
+///
+/// let data = SensorData::new("".to_string(), Utc::now()).with_co2(60_000.0, "ppm");
+///
+/// let report = ValidationReport::of(&data);
+/// let paths: Vec<&str> = report.errors().iter().map(|e| e.path.as_str()).collect();
+/// assert_eq!(paths, ["device_id", "co2.value"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ValidationReport {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationReport {
+    /// Creates an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates every field of the given sensor data against the default
+    /// ranges and collects all errors. The report is empty when the data is
+    /// valid.
+    ///
+    /// A measurement whose unit cannot be parsed is not range-checked.
+    pub fn of(data: &SensorData) -> Self {
+        Self::with_policy(data, &ValidationPolicy::default(), &SystemClock)
+    }
+
+    /// Same as `of`, checking values against the ranges the policy sets for
+    /// the device and the timestamp against `clock` and the timestamp rules
+    /// of the policy.
+    pub fn with_policy(data: &SensorData, policy: &ValidationPolicy, clock: &dyn Clock) -> Self {
+        let limits = policy.limits_for(&data.device_id);
+        let mut report = Self::new();
+        check_envelope(&mut report, data, policy, clock);
+        check_measurement(
+            &mut report,
+            "temperature",
+            data.temperature.as_ref(),
+            |unit| TemperatureUnit::try_from(unit),
+            |value, unit| TemperatureSensor::validate_value(value, unit, limits.temperature),
+        );
+        check_measurement(
+            &mut report,
+            "humidity",
+            data.humidity.as_ref(),
+            |unit| HumidityUnit::try_from(unit),
+            |value, _| HumiditySensor::validate_value(value, limits.humidity),
+        );
+        check_measurement(
+            &mut report,
+            "co2",
+            data.co2.as_ref(),
+            |unit| CO2Unit::try_from(unit),
+            |value, _| CO2Sensor::validate_value(value, limits.co2),
+        );
+        check_measurement(
+            &mut report,
+            "pressure",
+            data.pressure.as_ref(),
+            |unit| PressureUnit::try_from(unit),
+            |value, unit| PressureSensor::validate_value(value, unit, limits.pressure),
+        );
+        for (size, measurement) in particulates(data) {
+            check_measurement(
+                &mut report,
+                size.as_str(),
+                measurement,
+                |unit| ParticulateUnit::try_from(unit),
+                |value, _| ParticulateSensor::validate_value(value, size.range(&limits)),
+            );
+        }
+        check_measurement(
+            &mut report,
+            "voc",
+            data.voc.as_ref(),
+            |unit| VocUnit::try_from(unit),
+            |value, _| VocSensor::validate_value(value, limits.voc),
+        );
+        check_measurement(
+            &mut report,
+            "illuminance",
+            data.illuminance.as_ref(),
+            |unit| IlluminanceUnit::try_from(unit),
+            |value, _| IlluminanceSensor::validate_value(value, limits.illuminance),
+        );
+        check_measurement(
+            &mut report,
+            "sound_level",
+            data.sound_level.as_ref(),
+            |unit| SoundLevelUnit::try_from(unit),
+            |value, _| SoundLevelSensor::validate_value(value, limits.sound_level),
+        );
+        let mut names: Vec<&String> = data.additional_sensors.keys().collect();
+        names.sort();
+        for name in names {
+            check_additional(
+                &mut report,
+                name,
+                &data.additional_sensors[name],
+                &policy.additional_sensors,
+            );
+        }
+        report
+    }
+
+    /// Records an error for the given field path.
+    pub fn push(&mut self, path: impl Into<String>, error: SensorValidationError) {
+        self.errors.push(FieldError::new(path, error));
+    }
+
+    /// Returns `true` when no error has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the individual errors.
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// Consumes the report and returns the individual errors.
+    pub fn into_errors(self) -> Vec<FieldError> {
+        self.errors
+    }
+
+    /// Returns `Ok(value)` when the report is empty, the report otherwise.
+    pub fn into_result<T>(self, value: T) -> Result<T, Self> {
+        if self.is_empty() {
+            Ok(value)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl IntoIterator for ValidationReport {
+    type Item = FieldError;
+    type IntoIter = std::vec::IntoIter<FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+/// The typed sensors contained in a valid `SensorData`.
+///
+/// Additional sensors are validated against the sensor registry of the
+/// policy but have no typed form, so they are not part of the conversion.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::entities::SensorData;
+/// use domain::sensors::validation::ValidatedSensors;
+///
+/// let data = SensorData::new("device-001".to_string(), Utc::now())
+///     .with_temperature(22.5, "celsius")
+///     .with_co2(450.0, "ppm");
+///
+/// let sensors = ValidatedSensors::try_from(&data).expect("Valid sensor data");
+/// assert_eq!(sensors.temperature.unwrap().value(), 22.5);
+/// assert!(sensors.humidity.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidatedSensors {
+    pub temperature: Option<TemperatureSensor>,
+    pub humidity: Option<HumiditySensor>,
+    pub co2: Option<CO2Sensor>,
+    pub pressure: Option<PressureSensor>,
+    pub pm1_0: Option<ParticulateSensor>,
+    pub pm2_5: Option<ParticulateSensor>,
+    pub pm10: Option<ParticulateSensor>,
+    pub voc: Option<VocSensor>,
+    pub illuminance: Option<IlluminanceSensor>,
+    pub sound_level: Option<SoundLevelSensor>,
+}
+
+impl TryFrom<&SensorData> for ValidatedSensors {
+    type Error = ValidationReport;
+
+    /// Validates the sensor data against the default ranges and builds its
+    /// typed sensors.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationReport` listing every problem found rather than
+    /// only the first one.
+    fn try_from(data: &SensorData) -> Result<Self, Self::Error> {
+        Self::with_policy(data, &ValidationPolicy::default(), &SystemClock)
+    }
+}
+
+impl ValidatedSensors {
+    /// Validates the sensor data against the policy, with timestamps checked
+    /// against `clock`, and builds its typed sensors.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationReport` listing every problem found.
+    pub fn with_policy(
+        data: &SensorData,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
+    ) -> Result<Self, ValidationReport> {
+        // 検証とコンストラクタで同じ時刻を使う
+        let clock = FixedClock::new(clock.now());
+        ValidationReport::with_policy(data, policy, &clock).into_result(())?;
+
+        // 全ての項目を検証済みのため、以降のコンストラクタは失敗しない
+        let particulate = |size: ParticleSize, m: Option<&SensorMeasurement>| {
+            build_sensor(
+                size.as_str(),
+                m,
+                |unit| ParticulateUnit::try_from(unit),
+                |value, unit| {
+                    ParticulateSensor::with_policy(
+                        data.device_id.clone(),
+                        data.timestamp,
+                        size,
+                        value,
+                        unit,
+                        policy,
+                        &clock,
+                    )
+                },
+            )
+        };
+        Ok(Self {
+            temperature: build_sensor(
+                "temperature",
+                data.temperature.as_ref(),
+                |unit| TemperatureUnit::try_from(unit),
+                |value, unit| {
+                    TemperatureSensor::with_policy(
+                        data.device_id.clone(),
+                        data.timestamp,
+                        value,
+                        unit,
+                        policy,
+                        &clock,
+                    )
+                },
+            )?,
+            humidity: build_sensor(
+                "humidity",
+                data.humidity.as_ref(),
+                |unit| HumidityUnit::try_from(unit),
+                |value, unit| {
+                    HumiditySensor::with_policy(
+                        data.device_id.clone(),
+                        data.timestamp,
+                        value,
+                        unit,
+                        policy,
+                        &clock,
+                    )
+                },
+            )?,
+            co2: build_sensor(
+                "co2",
+                data.co2.as_ref(),
+                |unit| CO2Unit::try_from(unit),
+                |value, unit| {
+                    CO2Sensor::with_policy(
+                        data.device_id.clone(),
+                        data.timestamp,
+                        value,
+                        unit,
+                        policy,
+                        &clock,
+                    )
+                },
+            )?,
+            pressure: build_sensor(
+                "pressure",
+                data.pressure.as_ref(),
+                |unit| PressureUnit::try_from(unit),
+                |value, unit| {
+                    PressureSensor::with_policy(
+                        data.device_id.clone(),
+                        data.timestamp,
+                        value,
+                        unit,
+                        policy,
+                        &clock,
+                    )
+                },
+            )?,
+            pm1_0: particulate(ParticleSize::Pm1_0, data.pm1_0.as_ref())?,
+            pm2_5: particulate(ParticleSize::Pm2_5, data.pm2_5.as_ref())?,
+            pm10: particulate(ParticleSize::Pm10, data.pm10.as_ref())?,
+            voc: build_sensor(
+                "voc",
+                data.voc.as_ref(),
+                |unit| VocUnit::try_from(unit),
+                |value, unit| {
+                    VocSensor::with_policy(
+                        data.device_id.clone(),
+                        data.timestamp,
+                        value,
+                        unit,
+                        policy,
+                        &clock,
+                    )
+                },
+            )?,
+            illuminance: build_sensor(
+                "illuminance",
+                data.illuminance.as_ref(),
+                |unit| IlluminanceUnit::try_from(unit),
+                |value, unit| {
+                    IlluminanceSensor::with_policy(
+                        data.device_id.clone(),
+                        data.timestamp,
+                        value,
+                        unit,
+                        policy,
+                        &clock,
+                    )
+                },
+            )?,
+            sound_level: build_sensor(
+                "sound_level",
+                data.sound_level.as_ref(),
+                |unit| SoundLevelUnit::try_from(unit),
+                |value, unit| {
+                    SoundLevelSensor::with_policy(
+                        data.device_id.clone(),
+                        data.timestamp,
+                        value,
+                        unit,
+                        policy,
+                        &clock,
+                    )
+                },
+            )?,
+        })
+    }
+}
+
+/// Checks the fields shared by every measurement.
+fn check_envelope(
+    report: &mut ValidationReport,
+    data: &SensorData,
+    policy: &ValidationPolicy,
+    clock: &dyn Clock,
+) {
+    if data.device_id.is_empty() {
+        report.push("device_id", SensorValidationError::EmptyDeviceId);
+    }
+
+    if let Err(e) = policy.timestamps.check(data.timestamp, clock.now()) {
+        report.push("timestamp", e);
+    }
+}
+
+/// Pairs every particle size class with its measurement in the data.
+fn particulates(data: &SensorData) -> [(ParticleSize, Option<&SensorMeasurement>); 3] {
+    [
+        (ParticleSize::Pm1_0, data.pm1_0.as_ref()),
+        (ParticleSize::Pm2_5, data.pm2_5.as_ref()),
+        (ParticleSize::Pm10, data.pm10.as_ref()),
+    ]
+}
+
+/// Checks an additional sensor against the kind registered under its name,
+/// recording failures under `additional_sensors.<name>`. Sensors of unknown
+/// kinds are rejected or skipped as the registry says.
+fn check_additional(
+    report: &mut ValidationReport,
+    name: &str,
+    measurement: &SensorMeasurement,
+    registry: &SensorRegistry,
+) {
+    let field = format!("additional_sensors.{}", name);
+    match registry.get(name) {
+        Some(kind) => check_measurement(
+            report,
+            &field,
+            Some(measurement),
+            |unit| kind.factor(unit),
+            |value, _| kind.validate_value(value, &measurement.unit),
+        ),
+        None if registry.unknown == UnknownKinds::Reject => {
+            report.push(field, SensorValidationError::UnknownKind(name.to_string()));
+        }
+        None => {}
+    }
+}
+
+/// Parses the unit and checks the value of an optional measurement,
+/// recording failures under `<field>.unit` and `<field>.value`.
+fn check_measurement<U>(
+    report: &mut ValidationReport,
+    field: &str,
+    measurement: Option<&SensorMeasurement>,
+    parse_unit: impl Fn(&str) -> Result<U, SensorValidationError>,
+    validate_value: impl Fn(f64, U) -> Result<(), SensorValidationError>,
+) {
+    let Some(measurement) = measurement else {
+        return;
+    };
+    let unit = match parse_unit(measurement.unit.as_str()) {
+        Ok(unit) => unit,
+        Err(e) => {
+            report.push(format!("{}.unit", field), e);
+            return;
+        }
+    };
+    if let Err(e) = validate_value(measurement.value, unit) {
+        report.push(format!("{}.value", field), e);
+    }
+}
+
+/// Builds the typed sensor of an optional measurement from its parsed unit,
+/// reporting a failure under `field`.
+fn build_sensor<U, S>(
+    field: &str,
+    measurement: Option<&SensorMeasurement>,
+    parse_unit: impl Fn(&str) -> Result<U, SensorValidationError>,
+    build: impl Fn(f64, U) -> Result<S, SensorValidationError>,
+) -> Result<Option<S>, ValidationReport> {
+    measurement
+        .map(|m| build(m.value, parse_unit(m.unit.as_str())?))
+        .transpose()
+        .map_err(|e| {
+            let mut report = ValidationReport::new();
+            report.push(field, e);
+            report
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensors::{policy::ValueRange, registry::SensorKind};
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn paths(report: &ValidationReport) -> Vec<&str> {
+        report.errors().iter().map(|e| e.path.as_str()).collect()
+    }
+
+    mod validated_sensors_try_from {
+        use super::*;
+
+        #[test]
+        fn success_with_all_sensor_types() {
+            let data = SensorData::new("device-001".to_string(), Utc::now())
+                .with_temperature(22.5, "celsius")
+                .with_humidity(60.0, "percent")
+                .with_co2(450.0, "ppm")
+                .with_pressure(29.92, "inHg")
+                .with_pm2_5(12.0, "ug/m3")
+                .with_additional_sensor("wind_speed", 3.5, "m/s");
+
+            let sensors = ValidatedSensors::try_from(&data).unwrap();
+
+            let temperature = sensors.temperature.unwrap();
+            assert_eq!(temperature.device_id(), "device-001");
+            assert_eq!(temperature.unit(), TemperatureUnit::Celsius);
+            assert_eq!(sensors.humidity.unwrap().unit(), HumidityUnit::Percent);
+            assert_eq!(sensors.co2.unwrap().value(), 450.0);
+            assert_eq!(
+                sensors.pressure.unwrap().unit(),
+                PressureUnit::InchOfMercury
+            );
+            assert_eq!(sensors.pm2_5.unwrap().size(), ParticleSize::Pm2_5);
+            assert!(sensors.pm10.is_none());
+        }
+
+        #[test]
+        fn success_without_measurements() {
+            let data = SensorData::new("device-001".to_string(), Utc::now());
+
+            let sensors = ValidatedSensors::try_from(&data).unwrap();
+
+            assert_eq!(
+                sensors,
+                ValidatedSensors {
+                    temperature: None,
+                    humidity: None,
+                    co2: None,
+                    pressure: None,
+                    pm1_0: None,
+                    pm2_5: None,
+                    pm10: None,
+                    voc: None,
+                    illuminance: None,
+                    sound_level: None,
+                }
+            );
+        }
+
+        #[test]
+        fn fails_with_empty_device_id() {
+            let data = SensorData::new("".to_string(), Utc::now());
+
+            let report = ValidatedSensors::try_from(&data).unwrap_err();
+
+            assert_eq!(
+                report.errors(),
+                [FieldError::new(
+                    "device_id",
+                    SensorValidationError::EmptyDeviceId
+                )]
+            );
+        }
+
+        #[test]
+        fn fails_with_invalid_unit() {
+            let data =
+                SensorData::new("device-001".to_string(), Utc::now()).with_humidity(50.0, "ratio");
+
+            let report = ValidatedSensors::try_from(&data).unwrap_err();
+
+            assert_eq!(
+                report.errors(),
+                [FieldError::new(
+                    "humidity.unit",
+                    SensorValidationError::InvalidUnit("ratio".to_string())
+                )]
+            );
+        }
+    }
+
+    mod validation_report_of {
+        use super::*;
+
+        #[test]
+        fn is_empty_for_valid_data() {
+            let data = SensorData::new("device-001".to_string(), Utc::now())
+                .with_temperature(22.5, "celsius");
+
+            assert!(ValidationReport::of(&data).is_empty());
+        }
+
+        #[test]
+        fn collects_every_error() {
+            let future = Utc::now() + chrono::Duration::hours(1);
+            let data = SensorData::new("".to_string(), future)
+                .with_temperature(25.0, "rankine")
+                .with_humidity(150.0, "percent")
+                .with_co2(60_000.0, "ppm");
+
+            let report = ValidationReport::of(&data);
+
+            assert_eq!(
+                paths(&report),
+                [
+                    "device_id",
+                    "timestamp",
+                    "temperature.unit",
+                    "humidity.value",
+                    "co2.value"
+                ]
+            );
+        }
+
+        #[test]
+        fn skips_value_check_when_unit_is_invalid() {
+            let data =
+                SensorData::new("device-001".to_string(), Utc::now()).with_co2(60_000.0, "ppb");
+
+            let report = ValidationReport::of(&data);
+
+            assert_eq!(paths(&report), ["co2.unit"]);
+        }
+
+        #[test]
+        fn reports_particulates_by_size_class() {
+            let data = SensorData::new("device-001".to_string(), Utc::now())
+                .with_pm1_0(5.0, "mg/m3")
+                .with_pm2_5(12.0, "µg/m³")
+                .with_pm10(1_500.0, "µg/m³");
+
+            let report = ValidationReport::of(&data);
+
+            assert_eq!(paths(&report), ["pm1_0.unit", "pm10.value"]);
+        }
+
+        #[test]
+        fn checks_office_environment_measurements() {
+            let data = SensorData::new("device-001".to_string(), Utc::now())
+                .with_voc(70_000.0, "ppb")
+                .with_illuminance(500.0, "fc")
+                .with_sound_level(-3.5, "dBA");
+
+            let report = ValidationReport::of(&data);
+
+            assert_eq!(
+                paths(&report),
+                ["voc.value", "illuminance.unit", "sound_level.value"]
+            );
+        }
+
+        #[test]
+        fn checks_values_against_policy() {
+            let policy: ValidationPolicy = serde_json::from_value(json!({
+                "groups": { "greenhouse": { "humidity": { "min": 40.0, "max": 100.0 } } },
+                "devices": { "greenhouse-07": { "group": "greenhouse" } },
+            }))
+            .unwrap();
+            let data = SensorData::new("greenhouse-07".to_string(), Utc::now())
+                .with_humidity(30.0, "percent");
+
+            let report = ValidationReport::with_policy(&data, &policy, &SystemClock);
+
+            assert_eq!(
+                report.errors(),
+                [FieldError::new(
+                    "humidity.value",
+                    SensorValidationError::ValueOutOfRange {
+                        value: 30.0,
+                        min: 40.0,
+                        max: 100.0,
+                    }
+                )]
+            );
+            assert!(ValidationReport::of(&data).is_empty());
+        }
+
+        #[test]
+        fn checks_timestamp_against_clock_and_policy() {
+            let now = Utc::now();
+            let mut policy = ValidationPolicy::default();
+            policy.timestamps.future_tolerance_secs = 2;
+            policy.timestamps.max_age_days = Some(7);
+            let clock = FixedClock::new(now);
+            let report = |timestamp| {
+                let data = SensorData::new("device-001".to_string(), timestamp);
+                ValidationReport::with_policy(&data, &policy, &clock)
+            };
+
+            // デバイスの時計が少し進んでいても許容する
+            assert!(report(now + chrono::Duration::seconds(1)).is_empty());
+            assert_eq!(
+                report(now + chrono::Duration::seconds(3)).errors(),
+                [FieldError::new(
+                    "timestamp",
+                    SensorValidationError::FutureTimestamp
+                )]
+            );
+            assert_eq!(
+                report(now - chrono::Duration::days(8)).errors(),
+                [FieldError::new(
+                    "timestamp",
+                    SensorValidationError::StaleTimestamp { max_age_days: 7 }
+                )]
+            );
+        }
+
+        #[test]
+        fn checks_additional_sensors_against_registry() {
+            let mut policy = ValidationPolicy {
+                additional_sensors: SensorRegistry::new().with_kind(
+                    "wind_speed",
+                    SensorKind::new("m/s", ValueRange::new(0.0, 100.0)).with_unit("km/h", 0.25),
+                ),
+                ..ValidationPolicy::default()
+            };
+            let data = SensorData::new("device-001".to_string(), Utc::now())
+                .with_additional_sensor("wind_speed", 500.0, "km/h")
+                .with_additional_sensor("water_level", -3.5, "cm");
+
+            let report = ValidationReport::with_policy(&data, &policy, &SystemClock);
+            assert_eq!(
+                report.errors(),
+                [FieldError::new(
+                    "additional_sensors.wind_speed.value",
+                    SensorValidationError::ValueOutOfRange {
+                        value: 500.0,
+                        min: 0.0,
+                        max: 400.0,
+                    }
+                )]
+            );
+
+            // 未登録の種類は設定に応じて拒否する
+            policy.additional_sensors.unknown = UnknownKinds::Reject;
+            let report = ValidationReport::with_policy(&data, &policy, &SystemClock);
+            assert_eq!(
+                paths(&report),
+                [
+                    "additional_sensors.water_level",
+                    "additional_sensors.wind_speed.value"
+                ]
+            );
+        }
+
+        #[test]
+        fn into_result_returns_value_when_empty() {
+            assert_eq!(ValidationReport::new().into_result(1), Ok(1));
+        }
+    }
+
+    mod validation_report_format {
+        use super::*;
+
+        #[test]
+        fn serializes_stale_timestamp() {
+            let mut report = ValidationReport::new();
+            report.push(
+                "timestamp",
+                SensorValidationError::StaleTimestamp { max_age_days: 30 },
+            );
+
+            assert_eq!(
+                serde_json::to_value(&report).unwrap(),
+                json!({
+                    "errors": [{
+                        "path": "timestamp",
+                        "message": "timestamp must not be older than 30 days",
+                        "code": "stale_timestamp",
+                        "max_age_days": 30,
+                    }]
+                })
+            );
+        }
+
+        #[test]
+        fn serializes_unknown_kind() {
+            let mut report = ValidationReport::new();
+            report.push(
+                "additional_sensors.lux",
+                SensorValidationError::UnknownKind("lux".to_string()),
+            );
+
+            assert_eq!(
+                serde_json::to_value(&report).unwrap(),
+                json!({
+                    "errors": [{
+                        "path": "additional_sensors.lux",
+                        "message": "unknown sensor kind: lux",
+                        "code": "unknown_kind",
+                        "kind": "lux",
+                    }]
+                })
+            );
+        }
+
+        #[test]
+        fn displays_every_error() {
+            let data = SensorData::new("".to_string(), Utc::now()).with_co2(-1.0, "ppm");
+
+            let report = ValidationReport::of(&data);
+
+            assert_eq!(
+                report.to_string(),
+                "device_id: device_id must not be empty; co2.value: value -1 is out of range [0, 50000]"
+            );
+        }
+
+        #[test]
+        fn serializes_every_error() {
+            let data = SensorData::new("".to_string(), Utc::now())
+                .with_temperature(20.0, "rankine")
+                .with_co2(60_000.0, "ppm");
+
+            let report = ValidationReport::of(&data);
+
+            assert_eq!(
+                serde_json::to_value(&report).unwrap(),
+                json!({
+                    "errors": [
+                        {
+                            "path": "device_id",
+                            "message": "device_id must not be empty",
+                            "code": "empty_device_id",
+                        },
+                        {
+                            "path": "temperature.unit",
+                            "message": "invalid unit: rankine",
+                            "code": "invalid_unit",
+                            "unit": "rankine",
+                        },
+                        {
+                            "path": "co2.value",
+                            "message": "value 60000 is out of range [0, 50000]",
+                            "code": "value_out_of_range",
+                            "value": 60_000.0,
+                            "min": 0.0,
+                            "max": 50_000.0,
+                        },
+                    ]
+                })
+            );
+        }
+    }
+}
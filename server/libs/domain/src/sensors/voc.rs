@@ -0,0 +1,303 @@
+//! VOC Sensor Module
+//!
+//! Provides structures and validation for total volatile organic compound
+//! (TVOC) concentration sensor data.
+
+use chrono::{DateTime, Utc};
+
+use crate::clock::{Clock, SystemClock};
+use crate::sensors::error::SensorValidationError;
+use crate::sensors::policy::{ValidationPolicy, ValueRange};
+
+/// Enumeration representing the unit of TVOC measurement.
+///
+/// # Variants
+///
+/// * `Ppb` - Parts per billion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VocUnit {
+    Ppb,
+}
+
+impl VocUnit {
+    /// The unit measurements are stored in.
+    pub const CANONICAL: VocUnit = VocUnit::Ppb;
+
+    /// Returns the unit as a string slice.
+    ///
+    /// # Returns
+    ///
+    /// A static string slice representing the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::sensors::voc::VocUnit;
+    ///
+    /// let unit = VocUnit::Ppb;
+    /// assert_eq!(unit.as_str(), "ppb");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VocUnit::Ppb => "ppb",
+        }
+    }
+}
+
+impl TryFrom<&str> for VocUnit {
+    type Error = SensorValidationError;
+
+    /// Attempts to convert a string to VocUnit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The string to convert (case-insensitive, accepts "ppb")
+    ///
+    /// # Returns
+    ///
+    /// `Ok(VocUnit)` on success, `Err(SensorValidationError::InvalidUnit)` on failure.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "ppb" => Ok(VocUnit::Ppb),
+            _ => Err(SensorValidationError::InvalidUnit(value.to_string())),
+        }
+    }
+}
+
+/// Structure representing TVOC sensor data.
+///
+/// Holds TVOC concentration data collected from IoT devices.
+/// Validation is performed during instance creation.
+///
+/// # Fields
+///
+/// * `device_id` - Unique identifier for the device
+/// * `timestamp` - Measurement time (UTC)
+/// * `value` - TVOC concentration (0.0 to 60,000.0 ppb unless the validation
+///   policy sets another range)
+/// * `unit` - Unit of measurement
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use domain::sensors::voc::{VocSensor, VocUnit};
+///
+/// let sensor = VocSensor::new(
+///     "device-001".to_string(),
+///     Utc::now(),
+///     250.0,
+///     VocUnit::Ppb,
+/// ).expect("Valid sensor data");
+///
+/// assert_eq!(sensor.device_id(), "device-001");
+/// assert_eq!(sensor.value(), 250.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VocSensor {
+    device_id: String,
+    timestamp: DateTime<Utc>,
+    value: f64,
+    unit: VocUnit,
+}
+
+impl VocSensor {
+    /// Default range of allowed values (0.0 to 60,000.0 ppb)
+    pub const DEFAULT_RANGE: ValueRange = ValueRange::new(0.0, 60_000.0);
+
+    /// Creates a new VocSensor instance validated against the default range.
+    ///
+    /// # Arguments
+    ///
+    /// * `device_id` - Unique identifier for the device (must not be empty)
+    /// * `timestamp` - Measurement time (must not be in the future)
+    /// * `value` - TVOC concentration (0.0 to 60,000.0 ppb unless the
+    ///   validation policy sets another range)
+    /// * `unit` - Unit of measurement
+    ///
+    /// # Returns
+    ///
+    /// `Ok(VocSensor)` on success, `Err(SensorValidationError)` on validation failure.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::EmptyDeviceId` - If device_id is empty
+    /// * `SensorValidationError::FutureTimestamp` - If timestamp is in the future
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn new(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: VocUnit,
+    ) -> Result<Self, SensorValidationError> {
+        Self::with_policy(
+            device_id,
+            timestamp,
+            value,
+            unit,
+            &ValidationPolicy::default(),
+            &SystemClock,
+        )
+    }
+
+    /// Creates a new VocSensor instance validated against the range the policy
+    /// sets for the device.
+    ///
+    /// Timestamps are checked against `clock` and the timestamp rules of the
+    /// policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as `new`, and:
+    ///
+    /// * `SensorValidationError::StaleTimestamp` - If the policy sets a maximum
+    ///   age and the timestamp is older
+    pub fn with_policy(
+        device_id: String,
+        timestamp: DateTime<Utc>,
+        value: f64,
+        unit: VocUnit,
+        policy: &ValidationPolicy,
+        clock: &dyn Clock,
+    ) -> Result<Self, SensorValidationError> {
+        if device_id.is_empty() {
+            return Err(SensorValidationError::EmptyDeviceId);
+        }
+
+        policy.timestamps.check(timestamp, clock.now())?;
+
+        Self::validate_value(value, policy.limits_for(&device_id).voc)?;
+
+        Ok(Self {
+            device_id,
+            timestamp,
+            value,
+            unit,
+        })
+    }
+
+    /// Checks that a value lies within the given range.
+    ///
+    /// # Errors
+    ///
+    /// * `SensorValidationError::ValueOutOfRange` - If value is out of range
+    pub fn validate_value(value: f64, range: ValueRange) -> Result<(), SensorValidationError> {
+        if !range.contains(value) {
+            return Err(SensorValidationError::ValueOutOfRange {
+                value,
+                min: range.min,
+                max: range.max,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the device ID.
+    pub fn device_id(&self) -> &str {
+        &self.device_id
+    }
+
+    /// Returns the measurement timestamp.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Returns the TVOC concentration value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit of measurement.
+    pub fn unit(&self) -> VocUnit {
+        self.unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod voc_sensor_new {
+        use super::*;
+
+        #[test]
+        fn success_with_valid_data() {
+            let result = VocSensor::new("device-001".to_string(), Utc::now(), 250.0, VocUnit::Ppb);
+
+            assert!(result.is_ok());
+            let sensor = result.unwrap();
+            assert_eq!(sensor.device_id(), "device-001");
+            assert_eq!(sensor.value(), 250.0);
+            assert_eq!(sensor.unit(), VocUnit::Ppb);
+        }
+
+        #[test]
+        fn fails_with_empty_device_id() {
+            let result = VocSensor::new("".to_string(), Utc::now(), 250.0, VocUnit::Ppb);
+
+            assert_eq!(result, Err(SensorValidationError::EmptyDeviceId));
+        }
+
+        #[test]
+        fn fails_with_future_timestamp() {
+            let future = Utc::now() + chrono::Duration::hours(1);
+
+            let result = VocSensor::new("device-001".to_string(), future, 250.0, VocUnit::Ppb);
+
+            assert_eq!(result, Err(SensorValidationError::FutureTimestamp));
+        }
+
+        #[test]
+        fn fails_with_value_below_min() {
+            let result = VocSensor::new("device-001".to_string(), Utc::now(), -1.0, VocUnit::Ppb);
+
+            assert!(matches!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange { .. })
+            ));
+        }
+
+        #[test]
+        fn fails_with_value_above_max() {
+            let result =
+                VocSensor::new("device-001".to_string(), Utc::now(), 70_000.0, VocUnit::Ppb);
+
+            assert!(matches!(
+                result,
+                Err(SensorValidationError::ValueOutOfRange { .. })
+            ));
+        }
+
+        #[test]
+        fn success_with_boundary_values() {
+            let at =
+                |value| VocSensor::new("device-001".to_string(), Utc::now(), value, VocUnit::Ppb);
+
+            assert!(at(0.0).is_ok());
+            assert!(at(60_000.0).is_ok());
+        }
+    }
+
+    mod voc_unit {
+        use super::*;
+
+        #[test]
+        fn as_str_returns_ppb() {
+            assert_eq!(VocUnit::Ppb.as_str(), "ppb");
+        }
+
+        #[test]
+        fn try_from_is_case_insensitive() {
+            assert_eq!(VocUnit::try_from("ppb"), Ok(VocUnit::Ppb));
+            assert_eq!(VocUnit::try_from("PPB"), Ok(VocUnit::Ppb));
+        }
+
+        #[test]
+        fn try_from_invalid_unit() {
+            let result = VocUnit::try_from("ppm");
+
+            assert!(matches!(result, Err(SensorValidationError::InvalidUnit(_))));
+        }
+    }
+}
@@ -0,0 +1,16 @@
+-- Readings keep the fixed measurements in dedicated columns and every
+-- other sensor in a JSONB object keyed by sensor name.
+CREATE TABLE sensor_data (
+    id BIGSERIAL PRIMARY KEY,
+    device_id TEXT NOT NULL,
+    timestamp TIMESTAMPTZ NOT NULL,
+    temperature_value DOUBLE PRECISION,
+    temperature_unit TEXT,
+    humidity_value DOUBLE PRECISION,
+    humidity_unit TEXT,
+    co2_value DOUBLE PRECISION,
+    co2_unit TEXT,
+    additional_sensors JSONB NOT NULL DEFAULT '{}'::jsonb,
+    -- Duplicate suppression, also serves device + time range queries.
+    CONSTRAINT sensor_data_device_id_timestamp_key UNIQUE (device_id, timestamp)
+);
@@ -0,0 +1,4 @@
+-- Value and unit of each fixed measurement as received, for readings that
+-- were converted to a canonical unit. Keyed by measurement name.
+ALTER TABLE sensor_data
+    ADD COLUMN original_measurements JSONB NOT NULL DEFAULT '{}'::jsonb;
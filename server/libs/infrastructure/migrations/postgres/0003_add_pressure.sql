@@ -0,0 +1,4 @@
+-- Barometric pressure is a fixed measurement, stored like the others.
+ALTER TABLE sensor_data
+    ADD COLUMN pressure_value DOUBLE PRECISION,
+    ADD COLUMN pressure_unit TEXT;
@@ -0,0 +1,8 @@
+-- Particulate matter concentrations, one column pair per size class.
+ALTER TABLE sensor_data
+    ADD COLUMN pm1_0_value DOUBLE PRECISION,
+    ADD COLUMN pm1_0_unit TEXT,
+    ADD COLUMN pm2_5_value DOUBLE PRECISION,
+    ADD COLUMN pm2_5_unit TEXT,
+    ADD COLUMN pm10_value DOUBLE PRECISION,
+    ADD COLUMN pm10_unit TEXT;
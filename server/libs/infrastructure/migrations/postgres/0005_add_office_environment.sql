@@ -0,0 +1,8 @@
+-- TVOC, illuminance and sound level reported by office devices.
+ALTER TABLE sensor_data
+    ADD COLUMN voc_value DOUBLE PRECISION,
+    ADD COLUMN voc_unit TEXT,
+    ADD COLUMN illuminance_value DOUBLE PRECISION,
+    ADD COLUMN illuminance_unit TEXT,
+    ADD COLUMN sound_level_value DOUBLE PRECISION,
+    ADD COLUMN sound_level_unit TEXT;
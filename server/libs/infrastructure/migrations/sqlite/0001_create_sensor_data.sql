@@ -0,0 +1,16 @@
+-- Timestamps are stored as microseconds since the Unix epoch so that range
+-- filters and ordering compare them numerically.
+CREATE TABLE sensor_data (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    device_id TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    temperature_value REAL,
+    temperature_unit TEXT,
+    humidity_value REAL,
+    humidity_unit TEXT,
+    co2_value REAL,
+    co2_unit TEXT,
+    additional_sensors TEXT NOT NULL DEFAULT '{}',
+    -- Duplicate suppression, also serves device + time range queries.
+    UNIQUE (device_id, timestamp)
+);
@@ -0,0 +1,3 @@
+-- Value and unit of each fixed measurement as received, for readings that
+-- were converted to a canonical unit. Keyed by measurement name.
+ALTER TABLE sensor_data ADD COLUMN original_measurements TEXT NOT NULL DEFAULT '{}';
@@ -0,0 +1,3 @@
+-- Barometric pressure is a fixed measurement, stored like the others.
+ALTER TABLE sensor_data ADD COLUMN pressure_value REAL;
+ALTER TABLE sensor_data ADD COLUMN pressure_unit TEXT;
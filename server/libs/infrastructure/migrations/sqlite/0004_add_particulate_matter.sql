@@ -0,0 +1,7 @@
+-- Particulate matter concentrations, one column pair per size class.
+ALTER TABLE sensor_data ADD COLUMN pm1_0_value REAL;
+ALTER TABLE sensor_data ADD COLUMN pm1_0_unit TEXT;
+ALTER TABLE sensor_data ADD COLUMN pm2_5_value REAL;
+ALTER TABLE sensor_data ADD COLUMN pm2_5_unit TEXT;
+ALTER TABLE sensor_data ADD COLUMN pm10_value REAL;
+ALTER TABLE sensor_data ADD COLUMN pm10_unit TEXT;
@@ -0,0 +1,7 @@
+-- TVOC, illuminance and sound level reported by office devices.
+ALTER TABLE sensor_data ADD COLUMN voc_value REAL;
+ALTER TABLE sensor_data ADD COLUMN voc_unit TEXT;
+ALTER TABLE sensor_data ADD COLUMN illuminance_value REAL;
+ALTER TABLE sensor_data ADD COLUMN illuminance_unit TEXT;
+ALTER TABLE sensor_data ADD COLUMN sound_level_value REAL;
+ALTER TABLE sensor_data ADD COLUMN sound_level_unit TEXT;
@@ -0,0 +1,439 @@
+//! Backend-agnostic behaviour checks shared by every `SensorRepository`.
+//!
+//! Each check takes a freshly prepared, empty repository and asserts the
+//! contract documented on the trait. Backends run the whole suite with
+//! [`sensor_repository_conformance_tests!`], passing an async setup function
+//! that receives a unique test name to isolate its storage.
+//!
+//! Timestamps are truncated to `TIMESTAMP_PRECISION`, the precision every
+//! backend stores, so round-tripped readings compare exactly. The
+//! sub-millisecond checks pin down that truncation itself.
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use domain::entities::{OriginalMeasurement, SensorData, SensorMeasurement};
+use domain::repositories::{
+    PageRequest, QueryOptions, SaveOutcome, SensorRepository, SortOrder, TIMESTAMP_PRECISION,
+};
+use futures::TryStreamExt;
+use std::collections::HashSet;
+
+/// Generates one `#[tokio::test]` per conformance check.
+///
+/// `$setup` is an async function `fn(&str) -> impl SensorRepository`. The
+/// tests go into a `conformance` module unless another name is given, so one
+/// backend can run the suite against several configurations.
+macro_rules! sensor_repository_conformance_tests {
+    (@tests $prefix:ident $setup:path; $($check:ident),* $(,)?) => {
+        $(
+            #[tokio::test]
+            async fn $check() {
+                let repo = $setup(concat!(stringify!($prefix), "_", stringify!($check))).await;
+                $crate::persistence::conformance::$check(&repo).await;
+            }
+        )*
+    };
+    ($setup:path) => {
+        $crate::persistence::conformance::sensor_repository_conformance_tests!(
+            conformance: $setup
+        );
+    };
+    ($module:ident: $setup:path) => {
+        mod $module {
+            use super::*;
+
+            $crate::persistence::conformance::sensor_repository_conformance_tests!(
+                @tests $module $setup;
+                save_and_find_by_device_id,
+                round_trips_all_measurements,
+                round_trips_original_measurements,
+                find_by_device_id_isolates_devices,
+                save_reports_duplicate,
+                save_many_reports_duplicates,
+                sub_millisecond_readings_are_duplicates,
+                truncates_timestamps_to_milliseconds,
+                find_by_device_in_range,
+                find_page_by_device_id,
+                stream_by_device_id,
+                latest_for_device,
+                latest_for_all_devices,
+            );
+        }
+    };
+}
+
+pub(crate) use sensor_repository_conformance_tests;
+
+fn base_time() -> DateTime<Utc> {
+    Utc::now().trunc_subsecs(TIMESTAMP_PRECISION)
+}
+
+fn assert_same_measurement(
+    actual: Option<&SensorMeasurement>,
+    expected: Option<&SensorMeasurement>,
+) {
+    match (actual, expected) {
+        (Some(actual), Some(expected)) => {
+            assert_eq!(actual.value, expected.value);
+            assert_eq!(actual.unit, expected.unit);
+            assert_eq!(actual.original, expected.original);
+        }
+        (None, None) => {}
+        (actual, expected) => panic!("expected {:?}, got {:?}", expected, actual),
+    }
+}
+
+fn assert_same_reading(actual: &SensorData, expected: &SensorData) {
+    assert_eq!(actual.device_id, expected.device_id);
+    assert_eq!(actual.timestamp, expected.timestamp);
+    assert_same_measurement(actual.temperature.as_ref(), expected.temperature.as_ref());
+    assert_same_measurement(actual.humidity.as_ref(), expected.humidity.as_ref());
+    assert_same_measurement(actual.co2.as_ref(), expected.co2.as_ref());
+    assert_same_measurement(actual.pressure.as_ref(), expected.pressure.as_ref());
+    assert_same_measurement(actual.pm1_0.as_ref(), expected.pm1_0.as_ref());
+    assert_same_measurement(actual.pm2_5.as_ref(), expected.pm2_5.as_ref());
+    assert_same_measurement(actual.pm10.as_ref(), expected.pm10.as_ref());
+    assert_same_measurement(actual.voc.as_ref(), expected.voc.as_ref());
+    assert_same_measurement(actual.illuminance.as_ref(), expected.illuminance.as_ref());
+    assert_same_measurement(actual.sound_level.as_ref(), expected.sound_level.as_ref());
+
+    let actual_names: HashSet<_> = actual.additional_sensors.keys().collect();
+    let expected_names: HashSet<_> = expected.additional_sensors.keys().collect();
+    assert_eq!(actual_names, expected_names);
+    for (name, measurement) in &expected.additional_sensors {
+        assert_same_measurement(actual.additional_sensors.get(name), Some(measurement));
+    }
+}
+
+fn co2_values(readings: &[SensorData]) -> Vec<f64> {
+    readings
+        .iter()
+        .map(|r| r.co2.as_ref().expect("co2 should be set").value)
+        .collect()
+}
+
+pub(crate) async fn save_and_find_by_device_id(repo: &impl SensorRepository) {
+    let data =
+        SensorData::new("device-001".to_string(), base_time()).with_temperature(25.5, "celsius");
+
+    assert_eq!(repo.save(&data).await.unwrap(), SaveOutcome::Inserted);
+
+    let results = repo.find_by_device_id("device-001").await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_same_reading(&results[0], &data);
+
+    assert!(
+        repo.find_by_device_id("unknown-device")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
+
+pub(crate) async fn round_trips_all_measurements(repo: &impl SensorRepository) {
+    // 標準以外の単位もそのまま保持されること
+    let data = SensorData::new("device-002".to_string(), base_time())
+        .with_temperature(77.9, "fahrenheit")
+        .with_humidity(45.25, "percent")
+        .with_co2(612.0, "ppm")
+        .with_pressure(29.92, "inHg")
+        .with_pm1_0(4.0, "ug/m3")
+        .with_pm2_5(12.5, "µg/m³")
+        .with_pm10(18.25, "µg/m³")
+        .with_voc(250.0, "ppb")
+        .with_illuminance(480.5, "lx")
+        .with_sound_level(42.5, "dB(A)")
+        .with_additional_sensor("wind_speed", 3.5, "m/s")
+        .with_additional_sensor("water_level", -3.5, "cm");
+
+    repo.save(&data).await.unwrap();
+
+    let results = repo.find_by_device_id("device-002").await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_same_reading(&results[0], &data);
+
+    let latest = repo.latest_for_device("device-002").await.unwrap().unwrap();
+    assert_same_reading(&latest, &data);
+}
+
+pub(crate) async fn round_trips_original_measurements(repo: &impl SensorRepository) {
+    let original = |value: f64, unit: &str| {
+        Some(OriginalMeasurement {
+            value,
+            unit: unit.to_string(),
+        })
+    };
+    let mut data = SensorData::new("device-009".to_string(), base_time())
+        .with_temperature(25.0, "Celsius")
+        .with_humidity(40.0, "Percent")
+        .with_co2(500.0, "ppm")
+        .with_pressure(1013.25, "hPa")
+        .with_pm2_5(12.5, "µg/m³")
+        .with_additional_sensor("wind_speed", 3.5, "m/s");
+    data.temperature.as_mut().unwrap().original = original(77.0, "F");
+    data.co2.as_mut().unwrap().original = original(500.0, "PPM");
+    data.pressure.as_mut().unwrap().original = original(101.325, "kPa");
+    data.pm2_5.as_mut().unwrap().original = original(12.5, "ug/m3");
+    data.additional_sensors
+        .get_mut("wind_speed")
+        .unwrap()
+        .original = original(7.8, "mph");
+
+    repo.save(&data).await.unwrap();
+
+    let results = repo.find_by_device_id("device-009").await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_same_reading(&results[0], &data);
+    assert!(results[0].humidity.as_ref().unwrap().original.is_none());
+}
+
+pub(crate) async fn find_by_device_id_isolates_devices(repo: &impl SensorRepository) {
+    let now = base_time();
+    let readings = [
+        SensorData::new("device-a".to_string(), now - Duration::seconds(1)).with_co2(1.0, "ppm"),
+        SensorData::new("device-b".to_string(), now).with_co2(2.0, "ppm"),
+        SensorData::new("device-a".to_string(), now).with_co2(3.0, "ppm"),
+    ];
+    for data in &readings {
+        repo.save(data).await.unwrap();
+    }
+
+    let mut values = co2_values(&repo.find_by_device_id("device-a").await.unwrap());
+    values.sort_by(f64::total_cmp);
+    assert_eq!(values, vec![1.0, 3.0]);
+    assert_eq!(
+        co2_values(&repo.find_by_device_id("device-b").await.unwrap()),
+        vec![2.0]
+    );
+}
+
+pub(crate) async fn save_reports_duplicate(repo: &impl SensorRepository) {
+    let timestamp = base_time();
+    let first = SensorData::new("device-003".to_string(), timestamp).with_co2(400.0, "ppm");
+    let retry = SensorData::new("device-003".to_string(), timestamp).with_co2(999.0, "ppm");
+
+    assert_eq!(repo.save(&first).await.unwrap(), SaveOutcome::Inserted);
+    assert_eq!(repo.save(&retry).await.unwrap(), SaveOutcome::Duplicate);
+
+    // 最初に保存された値が残ること
+    let results = repo.find_by_device_id("device-003").await.unwrap();
+    assert_eq!(co2_values(&results), vec![400.0]);
+}
+
+pub(crate) async fn save_many_reports_duplicates(repo: &impl SensorRepository) {
+    assert!(repo.save_many(&[]).await.unwrap().is_empty());
+
+    let now = base_time();
+    let existing = SensorData::new("device-004".to_string(), now).with_co2(400.0, "ppm");
+    repo.save(&existing).await.unwrap();
+
+    let batch = vec![
+        SensorData::new("device-004".to_string(), now - Duration::seconds(1))
+            .with_co2(401.0, "ppm"),
+        existing.clone(),
+        SensorData::new("device-004".to_string(), now - Duration::seconds(2))
+            .with_co2(402.0, "ppm"),
+        // 同一バッチ内の重複は最初の 1 件だけが保存される
+        SensorData::new("device-004".to_string(), now - Duration::seconds(2))
+            .with_co2(403.0, "ppm"),
+    ];
+
+    let outcomes = repo.save_many(&batch).await.unwrap();
+    assert_eq!(
+        outcomes,
+        vec![
+            SaveOutcome::Inserted,
+            SaveOutcome::Duplicate,
+            SaveOutcome::Inserted,
+            SaveOutcome::Duplicate,
+        ]
+    );
+
+    let mut values = co2_values(&repo.find_by_device_id("device-004").await.unwrap());
+    values.sort_by(f64::total_cmp);
+    assert_eq!(values, vec![400.0, 401.0, 402.0]);
+}
+
+pub(crate) async fn sub_millisecond_readings_are_duplicates(repo: &impl SensorRepository) {
+    let timestamp = base_time() + Duration::microseconds(100);
+    let first = SensorData::new("device-010".to_string(), timestamp).with_co2(400.0, "ppm");
+    let close = SensorData::new(
+        "device-010".to_string(),
+        timestamp + Duration::microseconds(500),
+    )
+    .with_co2(401.0, "ppm");
+
+    assert_eq!(repo.save(&first).await.unwrap(), SaveOutcome::Inserted);
+    assert_eq!(repo.save(&close).await.unwrap(), SaveOutcome::Duplicate);
+    assert_eq!(
+        repo.save_many(std::slice::from_ref(&close)).await.unwrap(),
+        vec![SaveOutcome::Duplicate]
+    );
+
+    let results = repo.find_by_device_id("device-010").await.unwrap();
+    assert_eq!(co2_values(&results), vec![400.0]);
+}
+
+pub(crate) async fn truncates_timestamps_to_milliseconds(repo: &impl SensorRepository) {
+    let timestamp = DateTime::from_timestamp_micros(1_700_000_000_123_456).unwrap();
+    let data = SensorData::new("device-011".to_string(), timestamp);
+    repo.save(&data).await.unwrap();
+
+    let results = repo.find_by_device_id("device-011").await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].timestamp,
+        DateTime::from_timestamp_millis(1_700_000_000_123).unwrap()
+    );
+}
+
+pub(crate) async fn find_by_device_in_range(repo: &impl SensorRepository) {
+    let now = base_time();
+    let from = now - Duration::minutes(10);
+    for (offset, value) in [(-1, 1.0), (0, 2.0), (1, 3.0), (2, 4.0), (10, 5.0)] {
+        let data = SensorData::new("device-005".to_string(), from + Duration::minutes(offset))
+            .with_co2(value, "ppm");
+        repo.save(&data).await.unwrap();
+    }
+    let to = from + Duration::minutes(10);
+
+    // 範囲は [from, to) の半開区間
+    let ascending = repo
+        .find_by_device_in_range("device-005", from, to, QueryOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(co2_values(&ascending), vec![2.0, 3.0, 4.0]);
+
+    let options = QueryOptions::default()
+        .with_limit(2)
+        .with_order(SortOrder::Descending);
+    let latest_two = repo
+        .find_by_device_in_range("device-005", from, to, options)
+        .await
+        .unwrap();
+    assert_eq!(co2_values(&latest_two), vec![4.0, 3.0]);
+
+    let other_device = repo
+        .find_by_device_in_range("device-999", from, to, QueryOptions::default())
+        .await
+        .unwrap();
+    assert!(other_device.is_empty());
+}
+
+pub(crate) async fn find_page_by_device_id(repo: &impl SensorRepository) {
+    let now = base_time();
+    for seconds in 0..5 {
+        let data = SensorData::new("device-006".to_string(), now - Duration::seconds(seconds))
+            .with_co2(seconds as f64, "ppm");
+        repo.save(&data).await.unwrap();
+    }
+
+    for (order, expected) in [
+        (SortOrder::Ascending, vec![4.0, 3.0, 2.0, 1.0, 0.0]),
+        (SortOrder::Descending, vec![0.0, 1.0, 2.0, 3.0, 4.0]),
+    ] {
+        let mut seen = Vec::new();
+        let mut pages = 0;
+        let mut request = PageRequest::new(2).with_order(order);
+        loop {
+            let page = repo
+                .find_page_by_device_id("device-006", request.clone())
+                .await
+                .unwrap();
+            pages += 1;
+            assert!(page.items.len() <= 2);
+            seen.extend(page.items);
+            match page.next {
+                Some(token) => request = request.with_after(token),
+                None => break,
+            }
+        }
+
+        assert_eq!(co2_values(&seen), expected);
+        assert_eq!(pages, 3);
+    }
+
+    let empty = repo
+        .find_page_by_device_id("device-999", PageRequest::new(2))
+        .await
+        .unwrap();
+    assert!(empty.items.is_empty());
+    assert!(empty.next.is_none());
+}
+
+pub(crate) async fn stream_by_device_id(repo: &impl SensorRepository) {
+    let now = base_time();
+    for seconds in [2, 0, 1] {
+        let data = SensorData::new("device-007".to_string(), now - Duration::seconds(seconds))
+            .with_co2(seconds as f64, "ppm")
+            .with_pressure(1000.0, "hPa")
+            .with_additional_sensor("wind_speed", 3.5, "m/s");
+        repo.save(&data).await.unwrap();
+    }
+
+    let results: Vec<SensorData> = repo
+        .stream_by_device_id("device-007")
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(co2_values(&results), vec![2.0, 1.0, 0.0]);
+    assert!(
+        results
+            .iter()
+            .all(|r| r.pressure.as_ref().unwrap().unit == "hPa"
+                && r.additional_sensors["wind_speed"].unit == "m/s")
+    );
+
+    let empty: Vec<SensorData> = repo
+        .stream_by_device_id("device-999")
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(empty.is_empty());
+}
+
+pub(crate) async fn latest_for_device(repo: &impl SensorRepository) {
+    assert!(
+        repo.latest_for_device("device-008")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let now = base_time();
+    for (minutes, value) in [(5, 1.0), (0, 2.0), (3, 3.0)] {
+        let data = SensorData::new("device-008".to_string(), now - Duration::minutes(minutes))
+            .with_co2(value, "ppm");
+        repo.save(&data).await.unwrap();
+    }
+
+    let latest = repo.latest_for_device("device-008").await.unwrap().unwrap();
+    assert_eq!(latest.timestamp, now);
+    assert_eq!(latest.co2.unwrap().value, 2.0);
+}
+
+pub(crate) async fn latest_for_all_devices(repo: &impl SensorRepository) {
+    assert!(repo.latest_for_all_devices().await.unwrap().is_empty());
+
+    let now = base_time();
+    for (device_id, minutes, value) in [
+        ("device-c", 2, 1.0),
+        ("device-a", 5, 2.0),
+        ("device-c", 1, 3.0),
+        ("device-b", 0, 4.0),
+        ("device-a", 3, 5.0),
+    ] {
+        let data = SensorData::new(device_id.to_string(), now - Duration::minutes(minutes))
+            .with_co2(value, "ppm");
+        repo.save(&data).await.unwrap();
+    }
+
+    let latest = repo.latest_for_all_devices().await.unwrap();
+    let devices: Vec<_> = latest.iter().map(|r| r.device_id.as_str()).collect();
+    assert_eq!(devices, vec!["device-a", "device-b", "device-c"]);
+    assert_eq!(co2_values(&latest), vec![5.0, 4.0, 3.0]);
+}
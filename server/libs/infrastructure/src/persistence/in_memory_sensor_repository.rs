@@ -0,0 +1,237 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, SubsecRound, Utc};
+use domain::entities::SensorData;
+use domain::repositories::{
+    InvalidPageToken, Page, PageRequest, PageToken, QueryOptions, SaveOutcome, SensorDataStream,
+    SensorRepository, SortOrder, TIMESTAMP_PRECISION,
+};
+use futures::StreamExt;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+/// A `SensorRepository` that keeps readings in process memory.
+///
+/// Mirrors the behaviour of `MongoSensorRepository`: timestamps are stored
+/// with `TIMESTAMP_PRECISION`, readings are deduplicated on `device_id` +
+/// `timestamp`, and ties between equal timestamps are broken by insertion
+/// order. Intended for local development and tests.
+#[derive(Default)]
+pub struct InMemorySensorRepository {
+    inner: RwLock<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    next_id: u64,
+    records: Vec<Record>,
+}
+
+struct Record {
+    id: u64,
+    data: SensorData,
+}
+
+impl Record {
+    fn key(&self) -> (DateTime<Utc>, u64) {
+        (self.data.timestamp, self.id)
+    }
+}
+
+impl InMemorySensorRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total number of stored readings.
+    pub fn len(&self) -> usize {
+        self.inner.read().unwrap().records.len()
+    }
+
+    /// Returns `true` if no reading has been stored.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn insert(inner: &mut Inner, data: &SensorData) -> SaveOutcome {
+        let mut data = data.clone();
+        data.timestamp = data.timestamp.trunc_subsecs(TIMESTAMP_PRECISION);
+
+        let duplicate = inner
+            .records
+            .iter()
+            .any(|r| r.data.device_id == data.device_id && r.data.timestamp == data.timestamp);
+        if duplicate {
+            return SaveOutcome::Duplicate;
+        }
+
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.records.push(Record { id, data });
+        SaveOutcome::Inserted
+    }
+
+    /// Returns the records of a device sorted by `(timestamp, id)`.
+    fn sorted_for_device<'a>(
+        inner: &'a Inner,
+        device_id: &str,
+        order: SortOrder,
+    ) -> Vec<&'a Record> {
+        let mut records: Vec<&Record> = inner
+            .records
+            .iter()
+            .filter(|r| r.data.device_id == device_id)
+            .collect();
+        records.sort_by_key(|r| r.key());
+        if order == SortOrder::Descending {
+            records.reverse();
+        }
+        records
+    }
+}
+
+#[async_trait]
+impl SensorRepository for InMemorySensorRepository {
+    async fn save(&self, data: &SensorData) -> Result<SaveOutcome> {
+        let mut inner = self.inner.write().unwrap();
+        Ok(Self::insert(&mut inner, data))
+    }
+
+    async fn save_many(&self, data: &[SensorData]) -> Result<Vec<SaveOutcome>> {
+        let mut inner = self.inner.write().unwrap();
+        Ok(data.iter().map(|d| Self::insert(&mut inner, d)).collect())
+    }
+
+    async fn find_by_device_id(&self, device_id: &str) -> Result<Vec<SensorData>> {
+        let inner = self.inner.read().unwrap();
+        Ok(inner
+            .records
+            .iter()
+            .filter(|r| r.data.device_id == device_id)
+            .map(|r| r.data.clone())
+            .collect())
+    }
+
+    async fn find_by_device_in_range(
+        &self,
+        device_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        options: QueryOptions,
+    ) -> Result<Vec<SensorData>> {
+        let inner = self.inner.read().unwrap();
+        let limit = options.limit.map_or(usize::MAX, |l| l as usize);
+        Ok(Self::sorted_for_device(&inner, device_id, options.order)
+            .into_iter()
+            .filter(|r| r.data.timestamp >= from && r.data.timestamp < to)
+            .take(limit)
+            .map(|r| r.data.clone())
+            .collect())
+    }
+
+    async fn find_page_by_device_id(
+        &self,
+        device_id: &str,
+        request: PageRequest,
+    ) -> Result<Page<SensorData>> {
+        let after = match &request.after {
+            Some(token) => {
+                let id = token.id.parse::<u64>().map_err(|_| InvalidPageToken)?;
+                Some((token.timestamp, id))
+            }
+            None => None,
+        };
+
+        let inner = self.inner.read().unwrap();
+        let mut records: Vec<&Record> = Self::sorted_for_device(&inner, device_id, request.order)
+            .into_iter()
+            .filter(|r| match (after, request.order) {
+                (None, _) => true,
+                (Some(key), SortOrder::Ascending) => r.key() > key,
+                (Some(key), SortOrder::Descending) => r.key() < key,
+            })
+            .collect();
+
+        let limit = request.limit as usize;
+        let next = if records.len() > limit {
+            records.truncate(limit);
+            records
+                .last()
+                .map(|r| PageToken::new(r.data.timestamp, r.id.to_string()))
+        } else {
+            None
+        };
+
+        Ok(Page {
+            items: records.into_iter().map(|r| r.data.clone()).collect(),
+            next,
+        })
+    }
+
+    async fn stream_by_device_id(&self, device_id: &str) -> Result<SensorDataStream> {
+        let inner = self.inner.read().unwrap();
+        let data: Vec<SensorData> =
+            Self::sorted_for_device(&inner, device_id, SortOrder::Ascending)
+                .into_iter()
+                .map(|r| r.data.clone())
+                .collect();
+        Ok(futures::stream::iter(data.into_iter().map(Ok)).boxed())
+    }
+
+    async fn latest_for_device(&self, device_id: &str) -> Result<Option<SensorData>> {
+        let inner = self.inner.read().unwrap();
+        Ok(
+            Self::sorted_for_device(&inner, device_id, SortOrder::Descending)
+                .first()
+                .map(|r| r.data.clone()),
+        )
+    }
+
+    async fn latest_for_all_devices(&self) -> Result<Vec<SensorData>> {
+        let inner = self.inner.read().unwrap();
+        let mut latest: BTreeMap<&str, &Record> = BTreeMap::new();
+        for record in &inner.records {
+            let current = latest.entry(&record.data.device_id).or_insert(record);
+            if record.key() > current.key() {
+                *current = record;
+            }
+        }
+        Ok(latest.into_values().map(|r| r.data.clone()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn conformance_repository(_name: &str) -> InMemorySensorRepository {
+        InMemorySensorRepository::new()
+    }
+
+    crate::persistence::conformance::sensor_repository_conformance_tests!(conformance_repository);
+
+    #[tokio::test]
+    async fn test_save_sensor_data() {
+        let repo = InMemorySensorRepository::new();
+
+        let data =
+            SensorData::new("device-001".to_string(), Utc::now()).with_temperature(25.5, "celsius");
+
+        let result = repo.save(&data).await;
+        assert_eq!(result.unwrap(), SaveOutcome::Inserted);
+        assert_eq!(repo.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_page_rejects_foreign_token() {
+        let repo = InMemorySensorRepository::new();
+
+        let request = PageRequest::new(2).with_after(PageToken::new(Utc::now(), "not-a-number"));
+        let error = repo
+            .find_page_by_device_id("device-001", request)
+            .await
+            .unwrap_err();
+
+        assert!(error.downcast_ref::<InvalidPageToken>().is_some());
+    }
+}
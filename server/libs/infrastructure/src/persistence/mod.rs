@@ -1,4 +1,12 @@
+#[cfg(test)]
+mod conformance;
+pub mod in_memory_sensor_repository;
 pub mod models;
 pub mod mongo_sensor_repository;
+pub mod postgres_sensor_repository;
+pub mod sqlite_sensor_repository;
 
-pub use mongo_sensor_repository::MongoSensorRepository;
+pub use in_memory_sensor_repository::InMemorySensorRepository;
+pub use mongo_sensor_repository::{CollectionLayout, MongoSensorRepository};
+pub use postgres_sensor_repository::PostgresSensorRepository;
+pub use sqlite_sensor_repository::SqliteSensorRepository;
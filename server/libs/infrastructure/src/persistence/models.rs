@@ -1,11 +1,17 @@
 //! Persistance Module
 //!
 
-use chrono::{DateTime, Utc};
-use domain::entities::{SensorData, SensorMeasurement as DomainMeasurement};
+use chrono::{DateTime, SubsecRound, Utc};
+use domain::entities::{
+    OriginalMeasurement as DomainOriginal, SensorData, SensorMeasurement as DomainMeasurement,
+};
+use domain::repositories::TIMESTAMP_PRECISION;
 use mongodb::bson::oid::ObjectId;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sqlx::types::Json;
 use std::collections::HashMap;
+use std::fmt;
+use std::time::SystemTime;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SensorDataDocument {
@@ -14,6 +20,7 @@ pub struct SensorDataDocument {
 
     pub device_id: String,
 
+    #[serde(with = "bson_datetime")]
     pub timestamp: DateTime<Utc>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -25,6 +32,27 @@ pub struct SensorDataDocument {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub co2: Option<SensorMeasurement>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pressure: Option<SensorMeasurement>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pm1_0: Option<SensorMeasurement>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pm2_5: Option<SensorMeasurement>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pm10: Option<SensorMeasurement>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voc: Option<SensorMeasurement>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub illuminance: Option<SensorMeasurement>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sound_level: Option<SensorMeasurement>,
+
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub additional_sensors: HashMap<String, SensorMeasurement>,
 }
@@ -33,6 +61,16 @@ pub struct SensorDataDocument {
 pub struct SensorMeasurement {
     pub value: f64,
     pub unit: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original: Option<OriginalMeasurement>,
+}
+
+/// The value and unit a measurement was received with, before normalization.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OriginalMeasurement {
+    pub value: f64,
+    pub unit: String,
 }
 
 impl From<&SensorData> for SensorDataDocument {
@@ -40,10 +78,17 @@ impl From<&SensorData> for SensorDataDocument {
         Self {
             id: None,
             device_id: data.device_id.clone(),
-            timestamp: data.timestamp,
+            timestamp: data.timestamp.trunc_subsecs(TIMESTAMP_PRECISION),
             temperature: data.temperature.as_ref().map(SensorMeasurement::from),
             humidity: data.humidity.as_ref().map(SensorMeasurement::from),
             co2: data.co2.as_ref().map(SensorMeasurement::from),
+            pressure: data.pressure.as_ref().map(SensorMeasurement::from),
+            pm1_0: data.pm1_0.as_ref().map(SensorMeasurement::from),
+            pm2_5: data.pm2_5.as_ref().map(SensorMeasurement::from),
+            pm10: data.pm10.as_ref().map(SensorMeasurement::from),
+            voc: data.voc.as_ref().map(SensorMeasurement::from),
+            illuminance: data.illuminance.as_ref().map(SensorMeasurement::from),
+            sound_level: data.sound_level.as_ref().map(SensorMeasurement::from),
             additional_sensors: data
                 .additional_sensors
                 .iter()
@@ -61,6 +106,13 @@ impl From<SensorDataDocument> for SensorData {
             temperature: doc.temperature.map(DomainMeasurement::from),
             humidity: doc.humidity.map(DomainMeasurement::from),
             co2: doc.co2.map(DomainMeasurement::from),
+            pressure: doc.pressure.map(DomainMeasurement::from),
+            pm1_0: doc.pm1_0.map(DomainMeasurement::from),
+            pm2_5: doc.pm2_5.map(DomainMeasurement::from),
+            pm10: doc.pm10.map(DomainMeasurement::from),
+            voc: doc.voc.map(DomainMeasurement::from),
+            illuminance: doc.illuminance.map(DomainMeasurement::from),
+            sound_level: doc.sound_level.map(DomainMeasurement::from),
             additional_sensors: doc
                 .additional_sensors
                 .into_iter()
@@ -70,11 +122,228 @@ impl From<SensorDataDocument> for SensorData {
     }
 }
 
+/// Row of the relational `sensor_data` table.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SensorDataRow {
+    pub id: i64,
+    pub device_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub temperature_value: Option<f64>,
+    pub temperature_unit: Option<String>,
+    pub humidity_value: Option<f64>,
+    pub humidity_unit: Option<String>,
+    pub co2_value: Option<f64>,
+    pub co2_unit: Option<String>,
+    pub pressure_value: Option<f64>,
+    pub pressure_unit: Option<String>,
+    pub pm1_0_value: Option<f64>,
+    pub pm1_0_unit: Option<String>,
+    pub pm2_5_value: Option<f64>,
+    pub pm2_5_unit: Option<String>,
+    pub pm10_value: Option<f64>,
+    pub pm10_unit: Option<String>,
+    pub voc_value: Option<f64>,
+    pub voc_unit: Option<String>,
+    pub illuminance_value: Option<f64>,
+    pub illuminance_unit: Option<String>,
+    pub sound_level_value: Option<f64>,
+    pub sound_level_unit: Option<String>,
+    pub additional_sensors: Json<HashMap<String, SensorMeasurement>>,
+    pub original_measurements: Json<HashMap<String, OriginalMeasurement>>,
+}
+
+impl From<SensorDataRow> for SensorData {
+    fn from(row: SensorDataRow) -> Self {
+        let mut originals = row.original_measurements.0;
+        Self {
+            device_id: row.device_id,
+            timestamp: row.timestamp,
+            temperature: measurement(
+                row.temperature_value,
+                row.temperature_unit,
+                originals.remove("temperature"),
+            ),
+            humidity: measurement(
+                row.humidity_value,
+                row.humidity_unit,
+                originals.remove("humidity"),
+            ),
+            co2: measurement(row.co2_value, row.co2_unit, originals.remove("co2")),
+            pressure: measurement(
+                row.pressure_value,
+                row.pressure_unit,
+                originals.remove("pressure"),
+            ),
+            pm1_0: measurement(row.pm1_0_value, row.pm1_0_unit, originals.remove("pm1_0")),
+            pm2_5: measurement(row.pm2_5_value, row.pm2_5_unit, originals.remove("pm2_5")),
+            pm10: measurement(row.pm10_value, row.pm10_unit, originals.remove("pm10")),
+            voc: measurement(row.voc_value, row.voc_unit, originals.remove("voc")),
+            illuminance: measurement(
+                row.illuminance_value,
+                row.illuminance_unit,
+                originals.remove("illuminance"),
+            ),
+            sound_level: measurement(
+                row.sound_level_value,
+                row.sound_level_unit,
+                originals.remove("sound_level"),
+            ),
+            additional_sensors: row
+                .additional_sensors
+                .0
+                .into_iter()
+                .map(|(k, v)| (k, DomainMeasurement::from(v)))
+                .collect(),
+        }
+    }
+}
+
+/// Row of the SQLite `sensor_data` table.
+///
+/// `timestamp` holds microseconds since the Unix epoch.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SqliteSensorDataRow {
+    pub id: i64,
+    pub device_id: String,
+    pub timestamp: i64,
+    pub temperature_value: Option<f64>,
+    pub temperature_unit: Option<String>,
+    pub humidity_value: Option<f64>,
+    pub humidity_unit: Option<String>,
+    pub co2_value: Option<f64>,
+    pub co2_unit: Option<String>,
+    pub pressure_value: Option<f64>,
+    pub pressure_unit: Option<String>,
+    pub pm1_0_value: Option<f64>,
+    pub pm1_0_unit: Option<String>,
+    pub pm2_5_value: Option<f64>,
+    pub pm2_5_unit: Option<String>,
+    pub pm10_value: Option<f64>,
+    pub pm10_unit: Option<String>,
+    pub voc_value: Option<f64>,
+    pub voc_unit: Option<String>,
+    pub illuminance_value: Option<f64>,
+    pub illuminance_unit: Option<String>,
+    pub sound_level_value: Option<f64>,
+    pub sound_level_unit: Option<String>,
+    pub additional_sensors: Json<HashMap<String, SensorMeasurement>>,
+    pub original_measurements: Json<HashMap<String, OriginalMeasurement>>,
+}
+
+impl SqliteSensorDataRow {
+    /// Decodes the stored `timestamp` column.
+    pub fn decode_timestamp(&self) -> Result<DateTime<Utc>, InvalidStoredTimestamp> {
+        DateTime::from_timestamp_micros(self.timestamp)
+            .ok_or(InvalidStoredTimestamp(self.timestamp))
+    }
+}
+
+impl TryFrom<SqliteSensorDataRow> for SensorData {
+    type Error = InvalidStoredTimestamp;
+
+    fn try_from(row: SqliteSensorDataRow) -> Result<Self, Self::Error> {
+        let timestamp = row.decode_timestamp()?;
+        let mut originals = row.original_measurements.0;
+        Ok(Self {
+            device_id: row.device_id,
+            timestamp,
+            temperature: measurement(
+                row.temperature_value,
+                row.temperature_unit,
+                originals.remove("temperature"),
+            ),
+            humidity: measurement(
+                row.humidity_value,
+                row.humidity_unit,
+                originals.remove("humidity"),
+            ),
+            co2: measurement(row.co2_value, row.co2_unit, originals.remove("co2")),
+            pressure: measurement(
+                row.pressure_value,
+                row.pressure_unit,
+                originals.remove("pressure"),
+            ),
+            pm1_0: measurement(row.pm1_0_value, row.pm1_0_unit, originals.remove("pm1_0")),
+            pm2_5: measurement(row.pm2_5_value, row.pm2_5_unit, originals.remove("pm2_5")),
+            pm10: measurement(row.pm10_value, row.pm10_unit, originals.remove("pm10")),
+            voc: measurement(row.voc_value, row.voc_unit, originals.remove("voc")),
+            illuminance: measurement(
+                row.illuminance_value,
+                row.illuminance_unit,
+                originals.remove("illuminance"),
+            ),
+            sound_level: measurement(
+                row.sound_level_value,
+                row.sound_level_unit,
+                originals.remove("sound_level"),
+            ),
+            additional_sensors: row
+                .additional_sensors
+                .0
+                .into_iter()
+                .map(|(k, v)| (k, DomainMeasurement::from(v)))
+                .collect(),
+        })
+    }
+}
+
+/// Error returned when a stored SQLite timestamp lies outside the range of
+/// `DateTime<Utc>`. Carries the raw microsecond value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidStoredTimestamp(pub i64);
+
+impl fmt::Display for InvalidStoredTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stored timestamp {} is out of range", self.0)
+    }
+}
+
+impl std::error::Error for InvalidStoredTimestamp {}
+
+/// Rebuilds a measurement from its nullable value and unit columns.
+fn measurement(
+    value: Option<f64>,
+    unit: Option<String>,
+    original: Option<OriginalMeasurement>,
+) -> Option<DomainMeasurement> {
+    Some(DomainMeasurement {
+        value: value?,
+        unit: unit?,
+        original: original.map(DomainOriginal::from),
+    })
+}
+
+/// Collects the originals of the built-in measurements, keyed by
+/// measurement name, for the relational `original_measurements` column.
+pub(crate) fn original_measurements(
+    data: &SensorData,
+) -> HashMap<&'static str, OriginalMeasurement> {
+    [
+        ("temperature", &data.temperature),
+        ("humidity", &data.humidity),
+        ("co2", &data.co2),
+        ("pressure", &data.pressure),
+        ("pm1_0", &data.pm1_0),
+        ("pm2_5", &data.pm2_5),
+        ("pm10", &data.pm10),
+        ("voc", &data.voc),
+        ("illuminance", &data.illuminance),
+        ("sound_level", &data.sound_level),
+    ]
+    .into_iter()
+    .filter_map(|(name, m)| {
+        let original = m.as_ref()?.original.as_ref()?;
+        Some((name, OriginalMeasurement::from(original)))
+    })
+    .collect()
+}
+
 impl From<&DomainMeasurement> for SensorMeasurement {
     fn from(m: &DomainMeasurement) -> Self {
         Self {
             value: m.value,
             unit: m.unit.clone(),
+            original: m.original.as_ref().map(OriginalMeasurement::from),
         }
     }
 }
@@ -84,6 +353,110 @@ impl From<SensorMeasurement> for DomainMeasurement {
         Self {
             value: m.value,
             unit: m.unit,
+            original: m.original.map(DomainOriginal::from),
+        }
+    }
+}
+
+impl From<&DomainOriginal> for OriginalMeasurement {
+    fn from(m: &DomainOriginal) -> Self {
+        Self {
+            value: m.value,
+            unit: m.unit.clone(),
+        }
+    }
+}
+
+impl From<OriginalMeasurement> for DomainOriginal {
+    fn from(m: OriginalMeasurement) -> Self {
+        Self {
+            value: m.value,
+            unit: m.unit,
+        }
+    }
+}
+
+/// Converts a chrono timestamp into a BSON date so it can be compared in queries.
+pub(crate) fn to_bson_datetime(dt: DateTime<Utc>) -> mongodb::bson::DateTime {
+    mongodb::bson::DateTime::from(SystemTime::from(dt))
+}
+
+/// Stores timestamps as native BSON dates rather than strings, so that
+/// range filters and sorting compare them chronologically.
+///
+/// Documents written before the switch hold RFC 3339 strings, which are
+/// still read back until `MongoSensorRepository::migrate_string_timestamps`
+/// has rewritten them.
+mod bson_datetime {
+    use super::*;
+    use mongodb::bson::Bson;
+    use serde::de::Error;
+
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        to_bson_datetime(*dt).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Bson::deserialize(deserializer)? {
+            Bson::DateTime(dt) => Ok(DateTime::<Utc>::from(dt.to_system_time())),
+            Bson::String(s) => DateTime::parse_from_rfc3339(&s)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(D::Error::custom),
+            other => Err(D::Error::custom(format!(
+                "expected a date or an RFC 3339 string, found {:?}",
+                other.element_type()
+            ))),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::{self, Bson, doc};
+
+    #[test]
+    fn writes_timestamp_as_bson_date() {
+        let timestamp = DateTime::from_timestamp_millis(1_700_000_000_123).unwrap();
+        let data = SensorData::new("device-001".to_string(), timestamp);
+
+        let document = bson::to_document(&SensorDataDocument::from(&data)).unwrap();
+
+        assert_eq!(
+            document.get("timestamp"),
+            Some(&Bson::DateTime(to_bson_datetime(timestamp)))
+        );
+    }
+
+    #[test]
+    fn reads_document_with_string_timestamp() {
+        // タイムスタンプを RFC 3339 文字列で保存していた頃のドキュメント
+        let document = doc! {
+            "_id": ObjectId::new(),
+            "device_id": "device-001",
+            "timestamp": "2023-11-14T22:13:20.123456789Z",
+            "temperature": { "value": 25.5, "unit": "celsius" },
+        };
+
+        let data = SensorData::from(bson::from_document::<SensorDataDocument>(document).unwrap());
+
+        assert_eq!(
+            data.timestamp,
+            DateTime::from_timestamp_nanos(1_700_000_000_123_456_789)
+        );
+        assert_eq!(data.temperature.unwrap().value, 25.5);
+    }
+
+    #[test]
+    fn rejects_timestamp_of_other_type() {
+        let document = doc! { "device_id": "device-001", "timestamp": 1_700_000_000 };
+
+        assert!(bson::from_document::<SensorDataDocument>(document).is_err());
+    }
+}
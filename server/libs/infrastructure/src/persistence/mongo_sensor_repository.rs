@@ -1,28 +1,237 @@
-use crate::persistence::models::SensorDataDocument;
-use anyhow::Result;
+use crate::persistence::models::{SensorDataDocument, to_bson_datetime};
+use anyhow::{Result, bail};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use domain::entities::SensorData;
-use domain::repositories::SensorRepository;
-use futures::TryStreamExt;
-use mongodb::bson::doc;
-use mongodb::Collection;
+use domain::repositories::{
+    InvalidPageToken, Page, PageRequest, PageToken, QueryOptions, SaveOutcome, SensorDataStream,
+    SensorRepository, SortOrder,
+};
+use futures::{StreamExt, TryStreamExt};
+use mongodb::bson::{Document, doc, oid::ObjectId};
+use mongodb::error::{ErrorKind, InsertManyError, WriteFailure};
+use mongodb::options::{IndexOptions, TimeseriesGranularity, TimeseriesOptions};
+use mongodb::results::CollectionType;
+use mongodb::{Collection, Database, IndexModel};
+use std::collections::HashSet;
+
+/// Server error code reported when a unique index rejects a write.
+const DUPLICATE_KEY: i32 = 11000;
+
+/// Name of the compound index on `device_id` + `timestamp`.
+const DEVICE_TIMESTAMP_INDEX: &str = "device_id_timestamp";
+
+/// How the sensor data collection is laid out on the server.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CollectionLayout {
+    /// A regular collection with a unique `device_id` + `timestamp` index.
+    #[default]
+    Standard,
+    /// A native time-series collection with `timeField: timestamp` and
+    /// `metaField: device_id`.
+    ///
+    /// Time-series collections cannot carry unique indexes, so duplicates are
+    /// detected by looking up existing readings before inserting. Two writers
+    /// racing on the same reading may both store it.
+    TimeSeries,
+}
 
 pub struct MongoSensorRepository {
     collection: Collection<SensorDataDocument>,
+    layout: CollectionLayout,
 }
 
 impl MongoSensorRepository {
+    /// Wraps an existing standard collection. Call [`Self::ensure_indexes`]
+    /// before use, or prefer [`Self::initialize`].
     pub fn new(collection: Collection<SensorDataDocument>) -> Self {
-        Self { collection }
+        Self {
+            collection,
+            layout: CollectionLayout::Standard,
+        }
+    }
+
+    /// Creates the collection `name` with the given layout if it does not
+    /// exist yet, ensures its indexes, migrates string timestamps and returns
+    /// a repository on it.
+    ///
+    /// Safe to call on every startup. Fails if the collection already exists
+    /// with a different layout, since converting it requires migrating data.
+    pub async fn initialize(
+        database: &Database,
+        name: &str,
+        layout: CollectionLayout,
+    ) -> Result<Self> {
+        let existing = database
+            .list_collections()
+            .filter(doc! { "name": name })
+            .await?
+            .try_next()
+            .await?;
+
+        match (existing.map(|spec| spec.collection_type), layout) {
+            (None, CollectionLayout::TimeSeries) => {
+                let options = TimeseriesOptions::builder()
+                    .time_field("timestamp".to_string())
+                    .meta_field(Some("device_id".to_string()))
+                    .granularity(Some(TimeseriesGranularity::Seconds))
+                    .build();
+                database.create_collection(name).timeseries(options).await?;
+            }
+            // 通常のコレクションは最初の書き込みで暗黙に作成される
+            (None, CollectionLayout::Standard)
+            | (Some(CollectionType::Collection), CollectionLayout::Standard)
+            | (Some(CollectionType::Timeseries), CollectionLayout::TimeSeries) => {}
+            (Some(actual), expected) => bail!(
+                "collection {} already exists as {:?}, expected {:?} layout",
+                name,
+                actual,
+                expected
+            ),
+        }
+
+        let repository = Self {
+            collection: database.collection(name),
+            layout,
+        };
+        repository.ensure_indexes().await?;
+        repository.migrate_string_timestamps().await?;
+        Ok(repository)
+    }
+
+    /// Creates the compound `device_id` + `timestamp` index used by every
+    /// query. On a standard collection it is unique, which duplicate
+    /// suppression relies on. Safe to call on every startup.
+    pub async fn ensure_indexes(&self) -> Result<()> {
+        let index = IndexModel::builder()
+            .keys(doc! { "device_id": 1, "timestamp": 1 })
+            .options(
+                IndexOptions::builder()
+                    .name(DEVICE_TIMESTAMP_INDEX.to_string())
+                    .unique(self.layout == CollectionLayout::Standard)
+                    .build(),
+            )
+            .build();
+        self.collection.create_index(index).await?;
+        Ok(())
+    }
+
+    /// Rewrites timestamps stored as RFC 3339 strings into BSON dates and
+    /// returns the number of documents changed.
+    ///
+    /// Standard collections written before timestamps were stored as dates
+    /// hold strings, which range filters and sorting do not compare with
+    /// dates. Reads accept both forms, so the migration can run while the
+    /// service is up. It is a no-op once every document holds a date.
+    /// Time-series collections only accept dates and are never affected.
+    pub async fn migrate_string_timestamps(&self) -> Result<u64> {
+        let result = self
+            .collection
+            .update_many(
+                doc! { "timestamp": { "$type": "string" } },
+                vec![doc! { "$set": { "timestamp": { "$toDate": "$timestamp" } } }],
+            )
+            .await?;
+        Ok(result.modified_count)
+    }
+
+    /// Inserts the readings not already stored, comparing `device_id` and
+    /// `timestamp` at the millisecond precision BSON dates keep. Used for
+    /// time-series collections, which cannot enforce a unique index.
+    async fn insert_unseen(&self, data: &[SensorData]) -> Result<Vec<SaveOutcome>> {
+        let keys: Vec<Document> = data
+            .iter()
+            .map(|d| {
+                doc! {
+                    "device_id": &d.device_id,
+                    "timestamp": to_bson_datetime(d.timestamp),
+                }
+            })
+            .collect();
+        let existing: Vec<SensorDataDocument> = self
+            .collection
+            .find(doc! { "$or": keys })
+            .await?
+            .try_collect()
+            .await?;
+
+        let mut seen: HashSet<(String, i64)> = existing
+            .into_iter()
+            .map(|d| (d.device_id, d.timestamp.timestamp_millis()))
+            .collect();
+        let mut outcomes = Vec::with_capacity(data.len());
+        let mut documents = Vec::new();
+        for d in data {
+            // 同一バッチ内の重複も最初の 1 件だけを保存する
+            if seen.insert((d.device_id.clone(), d.timestamp.timestamp_millis())) {
+                outcomes.push(SaveOutcome::Inserted);
+                documents.push(SensorDataDocument::from(d));
+            } else {
+                outcomes.push(SaveOutcome::Duplicate);
+            }
+        }
+        if !documents.is_empty() {
+            self.collection.insert_many(documents).await?;
+        }
+        Ok(outcomes)
+    }
+}
+
+fn is_duplicate_key(error: &mongodb::error::Error) -> bool {
+    matches!(
+        error.kind.as_ref(),
+        ErrorKind::Write(WriteFailure::WriteError(e)) if e.code == DUPLICATE_KEY
+    )
+}
+
+fn sort_direction(order: SortOrder) -> i32 {
+    match order {
+        SortOrder::Ascending => 1,
+        SortOrder::Descending => -1,
     }
 }
 
 #[async_trait]
 impl SensorRepository for MongoSensorRepository {
-    async fn save(&self, data: &SensorData) -> Result<()> {
+    async fn save(&self, data: &SensorData) -> Result<SaveOutcome> {
+        if self.layout == CollectionLayout::TimeSeries {
+            let outcomes = self.insert_unseen(std::slice::from_ref(data)).await?;
+            return Ok(outcomes[0]);
+        }
         let document = SensorDataDocument::from(data);
-        self.collection.insert_one(document).await?;
-        Ok(())
+        match self.collection.insert_one(document).await {
+            Ok(_) => Ok(SaveOutcome::Inserted),
+            Err(e) if is_duplicate_key(&e) => Ok(SaveOutcome::Duplicate),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn save_many(&self, data: &[SensorData]) -> Result<Vec<SaveOutcome>> {
+        // insert_many は空の入力をエラーにするため何もせず返す
+        if data.is_empty() {
+            return Ok(Vec::new());
+        }
+        if self.layout == CollectionLayout::TimeSeries {
+            return self.insert_unseen(data).await;
+        }
+        let mut outcomes = vec![SaveOutcome::Inserted; data.len()];
+        let documents = data.iter().map(SensorDataDocument::from);
+        // ordered(false) で重複があっても残りのドキュメントは挿入を続ける
+        if let Err(e) = self.collection.insert_many(documents).ordered(false).await {
+            match e.kind.as_ref() {
+                ErrorKind::InsertMany(InsertManyError {
+                    write_errors: Some(errors),
+                    write_concern_error: None,
+                    ..
+                }) if errors.iter().all(|w| w.code == DUPLICATE_KEY) => {
+                    for error in errors {
+                        outcomes[error.index] = SaveOutcome::Duplicate;
+                    }
+                }
+                _ => return Err(e.into()),
+            }
+        }
+        Ok(outcomes)
     }
 
     async fn find_by_device_id(&self, device_id: &str) -> Result<Vec<SensorData>> {
@@ -32,12 +241,129 @@ impl SensorRepository for MongoSensorRepository {
         let sensor_data = documents.into_iter().map(SensorData::from).collect();
         Ok(sensor_data)
     }
+
+    async fn find_by_device_in_range(
+        &self,
+        device_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        options: QueryOptions,
+    ) -> Result<Vec<SensorData>> {
+        let filter = doc! {
+            "device_id": device_id,
+            "timestamp": {
+                "$gte": to_bson_datetime(from),
+                "$lt": to_bson_datetime(to),
+            },
+        };
+        let mut find = self
+            .collection
+            .find(filter)
+            .sort(doc! { "timestamp": sort_direction(options.order) });
+        if let Some(limit) = options.limit {
+            find = find.limit(i64::from(limit));
+        }
+        let documents: Vec<SensorDataDocument> = find.await?.try_collect().await?;
+        let sensor_data = documents.into_iter().map(SensorData::from).collect();
+        Ok(sensor_data)
+    }
+
+    async fn find_page_by_device_id(
+        &self,
+        device_id: &str,
+        request: PageRequest,
+    ) -> Result<Page<SensorData>> {
+        let limit = request.limit as usize;
+        let direction = sort_direction(request.order);
+        let mut filter = doc! { "device_id": device_id };
+        if let Some(after) = &request.after {
+            let id = ObjectId::parse_str(&after.id).map_err(|_| InvalidPageToken)?;
+            let timestamp = to_bson_datetime(after.timestamp);
+            let cmp = match request.order {
+                SortOrder::Ascending => "$gt",
+                SortOrder::Descending => "$lt",
+            };
+            // (timestamp, _id) の組で前ページの最後の位置より後ろを取得する
+            filter.insert(
+                "$or",
+                vec![
+                    doc! { "timestamp": { cmp: timestamp } },
+                    doc! { "timestamp": timestamp, "_id": { cmp: id } },
+                ],
+            );
+        }
+
+        let mut documents: Vec<SensorDataDocument> = self
+            .collection
+            .find(filter)
+            .sort(doc! { "timestamp": direction, "_id": direction })
+            .limit(request.limit as i64 + 1)
+            .await?
+            .try_collect()
+            .await?;
+
+        let next = if documents.len() > limit {
+            documents.truncate(limit);
+            documents
+                .last()
+                .and_then(|d| Some(PageToken::new(d.timestamp, d.id?.to_hex())))
+        } else {
+            None
+        };
+
+        Ok(Page {
+            items: documents.into_iter().map(SensorData::from).collect(),
+            next,
+        })
+    }
+
+    async fn stream_by_device_id(&self, device_id: &str) -> Result<SensorDataStream> {
+        let filter = doc! { "device_id": device_id };
+        let cursor = self
+            .collection
+            .find(filter)
+            .sort(doc! { "timestamp": 1, "_id": 1 })
+            .await?;
+        let stream = cursor
+            .map_ok(SensorData::from)
+            .map_err(anyhow::Error::from)
+            .boxed();
+        Ok(stream)
+    }
+
+    async fn latest_for_device(&self, device_id: &str) -> Result<Option<SensorData>> {
+        let filter = doc! { "device_id": device_id };
+        let document = self
+            .collection
+            .find_one(filter)
+            .sort(doc! { "timestamp": -1, "_id": -1 })
+            .await?;
+        Ok(document.map(SensorData::from))
+    }
+
+    async fn latest_for_all_devices(&self) -> Result<Vec<SensorData>> {
+        // device_id + timestamp のインデックスと同じ並びにして、全件のメモリ内ソートを避ける
+        let pipeline = vec![
+            doc! { "$sort": { "device_id": 1, "timestamp": 1 } },
+            doc! { "$group": { "_id": "$device_id", "latest": { "$last": "$$ROOT" } } },
+            doc! { "$replaceRoot": { "newRoot": "$latest" } },
+            doc! { "$sort": { "device_id": 1 } },
+        ];
+        let documents: Vec<SensorDataDocument> = self
+            .collection
+            .aggregate(pipeline)
+            .with_type::<SensorDataDocument>()
+            .await?
+            .try_collect()
+            .await?;
+        Ok(documents.into_iter().map(SensorData::from).collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
+    use chrono::Duration;
     use mongodb::Client;
     use std::sync::Once;
 
@@ -59,9 +385,38 @@ mod tests {
         // テスト前にコレクションをクリア
         collection.drop().await.ok();
 
-        (MongoSensorRepository::new(collection.clone()), collection)
+        let repo = MongoSensorRepository::new(collection.clone());
+        repo.ensure_indexes().await.expect("Failed to create indexes");
+        (repo, collection)
+    }
+
+    async fn conformance_repository(collection_name: &str) -> MongoSensorRepository {
+        setup_test_repository(collection_name).await.0
     }
 
+    crate::persistence::conformance::sensor_repository_conformance_tests!(conformance_repository);
+
+    async fn test_database() -> Database {
+        load_env();
+        let uri = std::env::var("MONGODB_URI").unwrap_or_else(|_| "mongodb://localhost:27017".to_string());
+        let client = Client::with_uri_str(&uri).await.expect("Failed to connect to MongoDB");
+        client.database("sensor_test_db")
+    }
+
+    async fn setup_time_series_repository(collection_name: &str) -> MongoSensorRepository {
+        let db = test_database().await;
+        // テスト前にコレクションを削除
+        db.collection::<SensorDataDocument>(collection_name).drop().await.ok();
+
+        MongoSensorRepository::initialize(&db, collection_name, CollectionLayout::TimeSeries)
+            .await
+            .expect("Failed to initialize time-series collection")
+    }
+
+    crate::persistence::conformance::sensor_repository_conformance_tests!(
+        time_series: setup_time_series_repository
+    );
+
     #[tokio::test]
     async fn test_save_sensor_data() {
         let (repo, collection) = setup_test_repository("test_save").await;
@@ -70,7 +425,7 @@ mod tests {
             .with_temperature(25.5, "celsius");
 
         let result = repo.save(&data).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), SaveOutcome::Inserted);
 
         // データが保存されたことを確認
         let count = collection.count_documents(doc! {}).await.unwrap();
@@ -87,7 +442,8 @@ mod tests {
         let device_id = "device-002";
         let data1 = SensorData::new(device_id.to_string(), Utc::now())
             .with_temperature(20.0, "celsius");
-        let data2 = SensorData::new(device_id.to_string(), Utc::now())
+        // 同一デバイス・同一時刻のデータは重複として扱われるため時刻をずらす
+        let data2 = SensorData::new(device_id.to_string(), Utc::now() - Duration::seconds(1))
             .with_humidity(55.0, "percent");
         let data3 = SensorData::new("other-device".to_string(), Utc::now())
             .with_co2(400.0, "ppm");
@@ -127,7 +483,12 @@ mod tests {
             .with_temperature(22.5, "celsius")
             .with_humidity(60.0, "percent")
             .with_co2(450.0, "ppm")
-            .with_additional_sensor("pressure", 1013.25, "hPa");
+            .with_pressure(1013.25, "hPa")
+            .with_pm2_5(12.5, "µg/m³")
+            .with_voc(250.0, "ppb")
+            .with_illuminance(500.0, "lux")
+            .with_sound_level(42.5, "dBA")
+            .with_additional_sensor("wind_speed", 3.5, "m/s");
 
         repo.save(&data).await.unwrap();
 
@@ -138,7 +499,12 @@ mod tests {
         assert!(saved.temperature.is_some());
         assert!(saved.humidity.is_some());
         assert!(saved.co2.is_some());
-        assert!(saved.additional_sensors.contains_key("pressure"));
+        assert!(saved.pressure.is_some());
+        assert!(saved.pm2_5.is_some());
+        assert!(saved.voc.is_some());
+        assert!(saved.illuminance.is_some());
+        assert!(saved.sound_level.is_some());
+        assert!(saved.additional_sensors.contains_key("wind_speed"));
 
         let temp = saved.temperature.as_ref().unwrap();
         assert_eq!(temp.value, 22.5);
@@ -147,4 +513,150 @@ mod tests {
         // クリーンアップ
         collection.drop().await.ok();
     }
+
+    #[tokio::test]
+    async fn test_save_many() {
+        let (repo, collection) = setup_test_repository("test_save_many").await;
+
+        let data: Vec<SensorData> = (0..3)
+            .map(|i| {
+                SensorData::new(format!("device-{}", i), Utc::now())
+                    .with_humidity(40.0 + i as f64, "percent")
+            })
+            .collect();
+
+        let outcomes = repo.save_many(&data).await.unwrap();
+        assert_eq!(outcomes, vec![SaveOutcome::Inserted; 3]);
+        assert!(repo.save_many(&[]).await.unwrap().is_empty());
+
+        let count = collection.count_documents(doc! {}).await.unwrap();
+        assert_eq!(count, 3);
+
+        // クリーンアップ
+        collection.drop().await.ok();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_creates_time_series_collection() {
+        let db = test_database().await;
+        let name = "test_initialize_time_series";
+        db.collection::<SensorDataDocument>(name).drop().await.ok();
+
+        // 2 回呼んでもエラーにならないこと
+        for _ in 0..2 {
+            MongoSensorRepository::initialize(&db, name, CollectionLayout::TimeSeries)
+                .await
+                .unwrap();
+        }
+
+        let spec = db
+            .list_collections()
+            .filter(doc! { "name": name })
+            .await
+            .unwrap()
+            .try_next()
+            .await
+            .unwrap()
+            .expect("collection should exist");
+        assert_eq!(spec.collection_type, CollectionType::Timeseries);
+        let timeseries = spec.options.timeseries.expect("timeseries options should be set");
+        assert_eq!(timeseries.time_field, "timestamp");
+        assert_eq!(timeseries.meta_field.as_deref(), Some("device_id"));
+
+        let index_names = db
+            .collection::<SensorDataDocument>(name)
+            .list_index_names()
+            .await
+            .unwrap();
+        assert!(index_names.iter().any(|n| n == DEVICE_TIMESTAMP_INDEX));
+
+        // クリーンアップ
+        db.collection::<SensorDataDocument>(name).drop().await.ok();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_creates_unique_index_on_standard_collection() {
+        let db = test_database().await;
+        let name = "test_initialize_standard";
+        db.collection::<SensorDataDocument>(name).drop().await.ok();
+
+        for _ in 0..2 {
+            MongoSensorRepository::initialize(&db, name, CollectionLayout::Standard)
+                .await
+                .unwrap();
+        }
+
+        let indexes: Vec<IndexModel> = db
+            .collection::<SensorDataDocument>(name)
+            .list_indexes()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let index = indexes
+            .iter()
+            .find(|i| {
+                i.options.as_ref().and_then(|o| o.name.as_deref()) == Some(DEVICE_TIMESTAMP_INDEX)
+            })
+            .expect("device_id_timestamp index should exist");
+        assert_eq!(index.options.as_ref().unwrap().unique, Some(true));
+
+        // クリーンアップ
+        db.collection::<SensorDataDocument>(name).drop().await.ok();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_migrates_string_timestamps() {
+        let db = test_database().await;
+        let name = "test_initialize_migrate";
+        db.collection::<SensorDataDocument>(name).drop().await.ok();
+
+        // タイムスタンプを RFC 3339 文字列で保存していた頃のドキュメント
+        let timestamp = DateTime::from_timestamp_millis(1_700_000_000_123).unwrap();
+        db.collection::<Document>(name)
+            .insert_one(doc! {
+                "device_id": "device-001",
+                "timestamp": "2023-11-14T22:13:20.123Z",
+                "co2": { "value": 400.0, "unit": "ppm" },
+            })
+            .await
+            .unwrap();
+
+        let repo = MongoSensorRepository::initialize(&db, name, CollectionLayout::Standard)
+            .await
+            .unwrap();
+
+        let results = repo
+            .find_by_device_in_range(
+                "device-001",
+                timestamp - Duration::seconds(1),
+                timestamp + Duration::seconds(1),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].timestamp, timestamp);
+        assert_eq!(repo.migrate_string_timestamps().await.unwrap(), 0);
+
+        // クリーンアップ
+        db.collection::<SensorDataDocument>(name).drop().await.ok();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_rejects_mismatched_layout() {
+        let db = test_database().await;
+        let name = "test_initialize_mismatch";
+        db.collection::<SensorDataDocument>(name).drop().await.ok();
+
+        MongoSensorRepository::initialize(&db, name, CollectionLayout::TimeSeries)
+            .await
+            .unwrap();
+        let result = MongoSensorRepository::initialize(&db, name, CollectionLayout::Standard).await;
+        assert!(result.is_err());
+
+        // クリーンアップ
+        db.collection::<SensorDataDocument>(name).drop().await.ok();
+    }
 }
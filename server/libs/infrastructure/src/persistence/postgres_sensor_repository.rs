@@ -0,0 +1,399 @@
+use crate::persistence::models::{SensorDataRow, SensorMeasurement, original_measurements};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, SubsecRound, Utc};
+use domain::entities::SensorData;
+use domain::repositories::{
+    InvalidPageToken, Page, PageRequest, PageToken, QueryOptions, SaveOutcome, SensorDataStream,
+    SensorRepository, SortOrder, TIMESTAMP_PRECISION,
+};
+use futures::StreamExt;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+use std::collections::{HashMap, HashSet};
+
+/// Schema migrations embedded at compile time.
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations/postgres");
+
+/// Columns selected into a `SensorDataRow`.
+const COLUMNS: &str = "id, device_id, timestamp, temperature_value, temperature_unit, \
+    humidity_value, humidity_unit, co2_value, co2_unit, pressure_value, pressure_unit, \
+    pm1_0_value, pm1_0_unit, pm2_5_value, pm2_5_unit, pm10_value, pm10_unit, \
+    voc_value, voc_unit, illuminance_value, illuminance_unit, sound_level_value, \
+    sound_level_unit, additional_sensors, original_measurements";
+
+/// Number of rows buffered ahead of a slow stream consumer.
+const STREAM_BUFFER: usize = 256;
+
+pub struct PostgresSensorRepository {
+    pool: PgPool,
+}
+
+impl PostgresSensorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Connects to the database at `url`.
+    pub async fn connect(url: &str) -> Result<Self> {
+        let pool = PgPoolOptions::new().connect(url).await?;
+        Ok(Self::new(pool))
+    }
+
+    /// Applies any pending schema migrations. Safe to call on every startup.
+    pub async fn migrate(&self) -> Result<()> {
+        MIGRATOR.run(&self.pool).await?;
+        Ok(())
+    }
+}
+
+/// Column-wise arrays of a batch, bound to a single `UNNEST` insert.
+#[derive(Default)]
+struct Columns {
+    device_id: Vec<String>,
+    timestamp: Vec<DateTime<Utc>>,
+    temperature_value: Vec<Option<f64>>,
+    temperature_unit: Vec<Option<String>>,
+    humidity_value: Vec<Option<f64>>,
+    humidity_unit: Vec<Option<String>>,
+    co2_value: Vec<Option<f64>>,
+    co2_unit: Vec<Option<String>>,
+    pressure_value: Vec<Option<f64>>,
+    pressure_unit: Vec<Option<String>>,
+    pm1_0_value: Vec<Option<f64>>,
+    pm1_0_unit: Vec<Option<String>>,
+    pm2_5_value: Vec<Option<f64>>,
+    pm2_5_unit: Vec<Option<String>>,
+    pm10_value: Vec<Option<f64>>,
+    pm10_unit: Vec<Option<String>>,
+    voc_value: Vec<Option<f64>>,
+    voc_unit: Vec<Option<String>>,
+    illuminance_value: Vec<Option<f64>>,
+    illuminance_unit: Vec<Option<String>>,
+    sound_level_value: Vec<Option<f64>>,
+    sound_level_unit: Vec<Option<String>>,
+    additional_sensors: Vec<String>,
+    original_measurements: Vec<String>,
+}
+
+impl Columns {
+    fn push(&mut self, data: &SensorData) -> Result<()> {
+        let additional: HashMap<&String, SensorMeasurement> = data
+            .additional_sensors
+            .iter()
+            .map(|(k, v)| (k, SensorMeasurement::from(v)))
+            .collect();
+
+        self.device_id.push(data.device_id.clone());
+        self.timestamp
+            .push(data.timestamp.trunc_subsecs(TIMESTAMP_PRECISION));
+        self.temperature_value
+            .push(data.temperature.as_ref().map(|m| m.value));
+        self.temperature_unit
+            .push(data.temperature.as_ref().map(|m| m.unit.clone()));
+        self.humidity_value
+            .push(data.humidity.as_ref().map(|m| m.value));
+        self.humidity_unit
+            .push(data.humidity.as_ref().map(|m| m.unit.clone()));
+        self.co2_value.push(data.co2.as_ref().map(|m| m.value));
+        self.co2_unit
+            .push(data.co2.as_ref().map(|m| m.unit.clone()));
+        self.pressure_value
+            .push(data.pressure.as_ref().map(|m| m.value));
+        self.pressure_unit
+            .push(data.pressure.as_ref().map(|m| m.unit.clone()));
+        self.pm1_0_value.push(data.pm1_0.as_ref().map(|m| m.value));
+        self.pm1_0_unit
+            .push(data.pm1_0.as_ref().map(|m| m.unit.clone()));
+        self.pm2_5_value.push(data.pm2_5.as_ref().map(|m| m.value));
+        self.pm2_5_unit
+            .push(data.pm2_5.as_ref().map(|m| m.unit.clone()));
+        self.pm10_value.push(data.pm10.as_ref().map(|m| m.value));
+        self.pm10_unit
+            .push(data.pm10.as_ref().map(|m| m.unit.clone()));
+        self.voc_value.push(data.voc.as_ref().map(|m| m.value));
+        self.voc_unit
+            .push(data.voc.as_ref().map(|m| m.unit.clone()));
+        self.illuminance_value
+            .push(data.illuminance.as_ref().map(|m| m.value));
+        self.illuminance_unit
+            .push(data.illuminance.as_ref().map(|m| m.unit.clone()));
+        self.sound_level_value
+            .push(data.sound_level.as_ref().map(|m| m.value));
+        self.sound_level_unit
+            .push(data.sound_level.as_ref().map(|m| m.unit.clone()));
+        self.additional_sensors
+            .push(serde_json::to_string(&additional)?);
+        self.original_measurements
+            .push(serde_json::to_string(&original_measurements(data))?);
+        Ok(())
+    }
+}
+
+fn direction(order: SortOrder) -> &'static str {
+    match order {
+        SortOrder::Ascending => "ASC",
+        SortOrder::Descending => "DESC",
+    }
+}
+
+#[async_trait]
+impl SensorRepository for PostgresSensorRepository {
+    async fn save(&self, data: &SensorData) -> Result<SaveOutcome> {
+        let outcomes = self.save_many(std::slice::from_ref(data)).await?;
+        Ok(outcomes[0])
+    }
+
+    async fn save_many(&self, data: &[SensorData]) -> Result<Vec<SaveOutcome>> {
+        if data.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut columns = Columns::default();
+        for d in data {
+            columns.push(d)?;
+        }
+
+        let inserted: Vec<(String, DateTime<Utc>)> = sqlx::query_as(
+            "INSERT INTO sensor_data (device_id, timestamp, temperature_value, temperature_unit, \
+                 humidity_value, humidity_unit, co2_value, co2_unit, pressure_value, \
+                 pressure_unit, pm1_0_value, pm1_0_unit, pm2_5_value, pm2_5_unit, pm10_value, \
+                 pm10_unit, voc_value, voc_unit, illuminance_value, illuminance_unit, \
+                 sound_level_value, sound_level_unit, additional_sensors, original_measurements) \
+             SELECT device_id, timestamp, temperature_value, temperature_unit, \
+                 humidity_value, humidity_unit, co2_value, co2_unit, pressure_value, \
+                 pressure_unit, pm1_0_value, pm1_0_unit, pm2_5_value, pm2_5_unit, pm10_value, \
+                 pm10_unit, voc_value, voc_unit, illuminance_value, illuminance_unit, \
+                 sound_level_value, sound_level_unit, additional_sensors::jsonb, \
+                 original_measurements::jsonb \
+             FROM UNNEST($1::text[], $2::timestamptz[], $3::float8[], $4::text[], \
+                 $5::float8[], $6::text[], $7::float8[], $8::text[], $9::float8[], $10::text[], \
+                 $11::float8[], $12::text[], $13::float8[], $14::text[], $15::float8[], \
+                 $16::text[], $17::float8[], $18::text[], $19::float8[], $20::text[], \
+                 $21::float8[], $22::text[], $23::text[], $24::text[]) \
+                 AS t(device_id, timestamp, temperature_value, temperature_unit, \
+                 humidity_value, humidity_unit, co2_value, co2_unit, pressure_value, \
+                 pressure_unit, pm1_0_value, pm1_0_unit, pm2_5_value, pm2_5_unit, pm10_value, \
+                 pm10_unit, voc_value, voc_unit, illuminance_value, illuminance_unit, \
+                 sound_level_value, sound_level_unit, additional_sensors, original_measurements) \
+             ON CONFLICT (device_id, timestamp) DO NOTHING \
+             RETURNING device_id, timestamp",
+        )
+        .bind(&columns.device_id)
+        .bind(&columns.timestamp)
+        .bind(&columns.temperature_value)
+        .bind(&columns.temperature_unit)
+        .bind(&columns.humidity_value)
+        .bind(&columns.humidity_unit)
+        .bind(&columns.co2_value)
+        .bind(&columns.co2_unit)
+        .bind(&columns.pressure_value)
+        .bind(&columns.pressure_unit)
+        .bind(&columns.pm1_0_value)
+        .bind(&columns.pm1_0_unit)
+        .bind(&columns.pm2_5_value)
+        .bind(&columns.pm2_5_unit)
+        .bind(&columns.pm10_value)
+        .bind(&columns.pm10_unit)
+        .bind(&columns.voc_value)
+        .bind(&columns.voc_unit)
+        .bind(&columns.illuminance_value)
+        .bind(&columns.illuminance_unit)
+        .bind(&columns.sound_level_value)
+        .bind(&columns.sound_level_unit)
+        .bind(&columns.additional_sensors)
+        .bind(&columns.original_measurements)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // バッチ内で同じキーが重複した場合は最初の 1 件だけを挿入扱いにする
+        let mut inserted: HashSet<(String, DateTime<Utc>)> = inserted.into_iter().collect();
+        let outcomes = columns
+            .device_id
+            .into_iter()
+            .zip(columns.timestamp)
+            .map(|key| {
+                if inserted.remove(&key) {
+                    SaveOutcome::Inserted
+                } else {
+                    SaveOutcome::Duplicate
+                }
+            })
+            .collect();
+        Ok(outcomes)
+    }
+
+    async fn find_by_device_id(&self, device_id: &str) -> Result<Vec<SensorData>> {
+        let sql = format!(
+            "SELECT {COLUMNS} FROM sensor_data WHERE device_id = $1 ORDER BY timestamp, id"
+        );
+        let rows: Vec<SensorDataRow> = sqlx::query_as(&sql)
+            .bind(device_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(SensorData::from).collect())
+    }
+
+    async fn find_by_device_in_range(
+        &self,
+        device_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        options: QueryOptions,
+    ) -> Result<Vec<SensorData>> {
+        let dir = direction(options.order);
+        let sql = format!(
+            "SELECT {COLUMNS} FROM sensor_data \
+             WHERE device_id = $1 AND timestamp >= $2 AND timestamp < $3 \
+             ORDER BY timestamp {dir}, id {dir} LIMIT $4"
+        );
+        let rows: Vec<SensorDataRow> = sqlx::query_as(&sql)
+            .bind(device_id)
+            .bind(from)
+            .bind(to)
+            .bind(options.limit.map(i64::from))
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(SensorData::from).collect())
+    }
+
+    async fn find_page_by_device_id(
+        &self,
+        device_id: &str,
+        request: PageRequest,
+    ) -> Result<Page<SensorData>> {
+        let after = match &request.after {
+            Some(token) => {
+                let id = token.id.parse::<i64>().map_err(|_| InvalidPageToken)?;
+                Some((token.timestamp, id))
+            }
+            None => None,
+        };
+        let cmp = match request.order {
+            SortOrder::Ascending => ">",
+            SortOrder::Descending => "<",
+        };
+        let dir = direction(request.order);
+        let sql = format!(
+            "SELECT {COLUMNS} FROM sensor_data \
+             WHERE device_id = $1 AND ($2::timestamptz IS NULL OR (timestamp, id) {cmp} ($2, $3)) \
+             ORDER BY timestamp {dir}, id {dir} LIMIT $4"
+        );
+        let mut rows: Vec<SensorDataRow> = sqlx::query_as(&sql)
+            .bind(device_id)
+            .bind(after.map(|(timestamp, _)| timestamp))
+            .bind(after.map(|(_, id)| id))
+            .bind(i64::from(request.limit) + 1)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let limit = request.limit as usize;
+        let next = if rows.len() > limit {
+            rows.truncate(limit);
+            rows.last()
+                .map(|r| PageToken::new(r.timestamp, r.id.to_string()))
+        } else {
+            None
+        };
+
+        Ok(Page {
+            items: rows.into_iter().map(SensorData::from).collect(),
+            next,
+        })
+    }
+
+    async fn stream_by_device_id(&self, device_id: &str) -> Result<SensorDataStream> {
+        let pool = self.pool.clone();
+        let device_id = device_id.to_string();
+        let (tx, rx) = tokio::sync::mpsc::channel(STREAM_BUFFER);
+
+        // カーソルがプールを借用するため、別タスクで読み出してチャネル経由で渡す
+        tokio::spawn(async move {
+            let sql = format!(
+                "SELECT {COLUMNS} FROM sensor_data WHERE device_id = $1 ORDER BY timestamp, id"
+            );
+            let mut rows = sqlx::query_as::<_, SensorDataRow>(&sql)
+                .bind(device_id)
+                .fetch(&pool);
+            while let Some(row) = rows.next().await {
+                let item = row.map(SensorData::from).map_err(anyhow::Error::from);
+                if tx.send(item).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let stream = futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        });
+        Ok(stream.boxed())
+    }
+
+    async fn latest_for_device(&self, device_id: &str) -> Result<Option<SensorData>> {
+        let sql = format!(
+            "SELECT {COLUMNS} FROM sensor_data WHERE device_id = $1 \
+             ORDER BY timestamp DESC, id DESC LIMIT 1"
+        );
+        let row: Option<SensorDataRow> = sqlx::query_as(&sql)
+            .bind(device_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.map(SensorData::from))
+    }
+
+    async fn latest_for_all_devices(&self) -> Result<Vec<SensorData>> {
+        let sql = format!(
+            "SELECT DISTINCT ON (device_id) {COLUMNS} FROM sensor_data \
+             ORDER BY device_id, timestamp DESC, id DESC"
+        );
+        let rows: Vec<SensorDataRow> = sqlx::query_as(&sql).fetch_all(&self.pool).await?;
+        Ok(rows.into_iter().map(SensorData::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::postgres::PgConnectOptions;
+    use std::str::FromStr;
+    use std::sync::Once;
+
+    static INIT: Once = Once::new();
+
+    fn load_env() {
+        INIT.call_once(|| {
+            dotenvy::dotenv().ok();
+        });
+    }
+
+    /// Creates a repository bound to a freshly created schema so tests can
+    /// run in parallel against the same database.
+    async fn setup_test_repository(schema: &str) -> PostgresSensorRepository {
+        load_env();
+        let url = std::env::var("POSTGRES_TEST_URL")
+            .unwrap_or_else(|_| "postgres://postgres@localhost:5432/sensor_test_db".to_string());
+
+        let admin = PgPool::connect(&url)
+            .await
+            .expect("Failed to connect to PostgreSQL");
+        // テスト前にスキーマを作り直す
+        sqlx::query(&format!("DROP SCHEMA IF EXISTS {schema} CASCADE"))
+            .execute(&admin)
+            .await
+            .unwrap();
+        sqlx::query(&format!("CREATE SCHEMA {schema}"))
+            .execute(&admin)
+            .await
+            .unwrap();
+
+        let options = PgConnectOptions::from_str(&url)
+            .unwrap()
+            .options([("search_path", schema)]);
+        let pool = PgPoolOptions::new().connect_with(options).await.unwrap();
+        let repo = PostgresSensorRepository::new(pool);
+        repo.migrate().await.expect("Failed to run migrations");
+        repo
+    }
+
+    crate::persistence::conformance::sensor_repository_conformance_tests!(setup_test_repository);
+}
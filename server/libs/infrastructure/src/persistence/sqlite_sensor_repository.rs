@@ -0,0 +1,370 @@
+use crate::persistence::models::{SensorMeasurement, SqliteSensorDataRow, original_measurements};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, SubsecRound, Utc};
+use domain::entities::SensorData;
+use domain::repositories::{
+    InvalidPageToken, Page, PageRequest, PageToken, QueryOptions, SaveOutcome, SensorDataStream,
+    SensorRepository, SortOrder, TIMESTAMP_PRECISION,
+};
+use futures::StreamExt;
+use sqlx::SqlitePool;
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Schema migrations embedded at compile time.
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+/// Columns selected into a `SqliteSensorDataRow`.
+const COLUMNS: &str = "id, device_id, timestamp, temperature_value, temperature_unit, \
+    humidity_value, humidity_unit, co2_value, co2_unit, pressure_value, pressure_unit, \
+    pm1_0_value, pm1_0_unit, pm2_5_value, pm2_5_unit, pm10_value, pm10_unit, \
+    voc_value, voc_unit, illuminance_value, illuminance_unit, sound_level_value, \
+    sound_level_unit, additional_sensors, original_measurements";
+
+/// Number of rows buffered ahead of a slow stream consumer.
+const STREAM_BUFFER: usize = 256;
+
+/// A `SensorRepository` backed by an embedded SQLite database file.
+///
+/// Intended for single-node deployments where running a database server is
+/// not worth it. The database is opened in WAL mode so readers do not block
+/// the writer. Timestamps are stored with microsecond precision.
+pub struct SqliteSensorRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSensorRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Opens, or creates, the database file at `path` in WAL mode.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .synchronous(SqliteSynchronous::Normal);
+        let pool = SqlitePoolOptions::new().connect_with(options).await?;
+        Ok(Self::new(pool))
+    }
+
+    /// Applies any pending schema migrations. Safe to call on every startup.
+    pub async fn migrate(&self) -> Result<()> {
+        MIGRATOR.run(&self.pool).await?;
+        Ok(())
+    }
+}
+
+fn direction(order: SortOrder) -> &'static str {
+    match order {
+        SortOrder::Ascending => "ASC",
+        SortOrder::Descending => "DESC",
+    }
+}
+
+#[async_trait]
+impl SensorRepository for SqliteSensorRepository {
+    async fn save(&self, data: &SensorData) -> Result<SaveOutcome> {
+        let outcomes = self.save_many(std::slice::from_ref(data)).await?;
+        Ok(outcomes[0])
+    }
+
+    async fn save_many(&self, data: &[SensorData]) -> Result<Vec<SaveOutcome>> {
+        let mut outcomes = Vec::with_capacity(data.len());
+        // 1 トランザクションでまとめて書き込む
+        let mut tx = self.pool.begin().await?;
+        for d in data {
+            let additional: HashMap<&String, SensorMeasurement> = d
+                .additional_sensors
+                .iter()
+                .map(|(k, v)| (k, SensorMeasurement::from(v)))
+                .collect();
+            let result = sqlx::query(
+                "INSERT INTO sensor_data (device_id, timestamp, temperature_value, \
+                     temperature_unit, humidity_value, humidity_unit, co2_value, co2_unit, \
+                     pressure_value, pressure_unit, pm1_0_value, pm1_0_unit, pm2_5_value, \
+                     pm2_5_unit, pm10_value, pm10_unit, voc_value, voc_unit, illuminance_value, \
+                     illuminance_unit, sound_level_value, sound_level_unit, additional_sensors, \
+                     original_measurements) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+                 ON CONFLICT (device_id, timestamp) DO NOTHING",
+            )
+            .bind(&d.device_id)
+            .bind(
+                d.timestamp
+                    .trunc_subsecs(TIMESTAMP_PRECISION)
+                    .timestamp_micros(),
+            )
+            .bind(d.temperature.as_ref().map(|m| m.value))
+            .bind(d.temperature.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.humidity.as_ref().map(|m| m.value))
+            .bind(d.humidity.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.co2.as_ref().map(|m| m.value))
+            .bind(d.co2.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.pressure.as_ref().map(|m| m.value))
+            .bind(d.pressure.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.pm1_0.as_ref().map(|m| m.value))
+            .bind(d.pm1_0.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.pm2_5.as_ref().map(|m| m.value))
+            .bind(d.pm2_5.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.pm10.as_ref().map(|m| m.value))
+            .bind(d.pm10.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.voc.as_ref().map(|m| m.value))
+            .bind(d.voc.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.illuminance.as_ref().map(|m| m.value))
+            .bind(d.illuminance.as_ref().map(|m| m.unit.as_str()))
+            .bind(d.sound_level.as_ref().map(|m| m.value))
+            .bind(d.sound_level.as_ref().map(|m| m.unit.as_str()))
+            .bind(serde_json::to_string(&additional)?)
+            .bind(serde_json::to_string(&original_measurements(d))?)
+            .execute(&mut *tx)
+            .await?;
+            outcomes.push(if result.rows_affected() == 1 {
+                SaveOutcome::Inserted
+            } else {
+                SaveOutcome::Duplicate
+            });
+        }
+        tx.commit().await?;
+        Ok(outcomes)
+    }
+
+    async fn find_by_device_id(&self, device_id: &str) -> Result<Vec<SensorData>> {
+        let sql =
+            format!("SELECT {COLUMNS} FROM sensor_data WHERE device_id = ? ORDER BY timestamp, id");
+        let rows: Vec<SqliteSensorDataRow> = sqlx::query_as(&sql)
+            .bind(device_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(SensorData::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn find_by_device_in_range(
+        &self,
+        device_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        options: QueryOptions,
+    ) -> Result<Vec<SensorData>> {
+        let dir = direction(options.order);
+        // LIMIT -1 は無制限を意味する
+        let sql = format!(
+            "SELECT {COLUMNS} FROM sensor_data \
+             WHERE device_id = ? AND timestamp >= ? AND timestamp < ? \
+             ORDER BY timestamp {dir}, id {dir} LIMIT ?"
+        );
+        let rows: Vec<SqliteSensorDataRow> = sqlx::query_as(&sql)
+            .bind(device_id)
+            .bind(from.timestamp_micros())
+            .bind(to.timestamp_micros())
+            .bind(options.limit.map_or(-1, i64::from))
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(SensorData::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn find_page_by_device_id(
+        &self,
+        device_id: &str,
+        request: PageRequest,
+    ) -> Result<Page<SensorData>> {
+        let after = match &request.after {
+            Some(token) => {
+                let id = token.id.parse::<i64>().map_err(|_| InvalidPageToken)?;
+                Some((token.timestamp.timestamp_micros(), id))
+            }
+            None => None,
+        };
+        let cmp = match request.order {
+            SortOrder::Ascending => ">",
+            SortOrder::Descending => "<",
+        };
+        let dir = direction(request.order);
+        let sql = format!(
+            "SELECT {COLUMNS} FROM sensor_data \
+             WHERE device_id = ?1 AND (?2 IS NULL OR (timestamp, id) {cmp} (?2, ?3)) \
+             ORDER BY timestamp {dir}, id {dir} LIMIT ?4"
+        );
+        let mut rows: Vec<SqliteSensorDataRow> = sqlx::query_as(&sql)
+            .bind(device_id)
+            .bind(after.map(|(timestamp, _)| timestamp))
+            .bind(after.map(|(_, id)| id))
+            .bind(i64::from(request.limit) + 1)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let limit = request.limit as usize;
+        let next = if rows.len() > limit {
+            rows.truncate(limit);
+            match rows.last() {
+                Some(r) => Some(PageToken::new(r.decode_timestamp()?, r.id.to_string())),
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        Ok(Page {
+            items: rows
+                .into_iter()
+                .map(SensorData::try_from)
+                .collect::<Result<_, _>>()?,
+            next,
+        })
+    }
+
+    async fn stream_by_device_id(&self, device_id: &str) -> Result<SensorDataStream> {
+        let pool = self.pool.clone();
+        let device_id = device_id.to_string();
+        let (tx, rx) = tokio::sync::mpsc::channel(STREAM_BUFFER);
+
+        // カーソルがプールを借用するため、別タスクで読み出してチャネル経由で渡す
+        tokio::spawn(async move {
+            let sql = format!(
+                "SELECT {COLUMNS} FROM sensor_data WHERE device_id = ? ORDER BY timestamp, id"
+            );
+            let mut rows = sqlx::query_as::<_, SqliteSensorDataRow>(&sql)
+                .bind(device_id)
+                .fetch(&pool);
+            while let Some(row) = rows.next().await {
+                let item = row
+                    .map_err(anyhow::Error::from)
+                    .and_then(|r| SensorData::try_from(r).map_err(anyhow::Error::from));
+                if tx.send(item).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let stream = futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        });
+        Ok(stream.boxed())
+    }
+
+    async fn latest_for_device(&self, device_id: &str) -> Result<Option<SensorData>> {
+        let sql = format!(
+            "SELECT {COLUMNS} FROM sensor_data WHERE device_id = ? \
+             ORDER BY timestamp DESC, id DESC LIMIT 1"
+        );
+        let row: Option<SqliteSensorDataRow> = sqlx::query_as(&sql)
+            .bind(device_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.map(SensorData::try_from).transpose()?)
+    }
+
+    async fn latest_for_all_devices(&self) -> Result<Vec<SensorData>> {
+        let sql = format!(
+            "SELECT {COLUMNS} FROM ( \
+                 SELECT *, ROW_NUMBER() OVER ( \
+                     PARTITION BY device_id ORDER BY timestamp DESC, id DESC \
+                 ) AS rank FROM sensor_data \
+             ) WHERE rank = 1 ORDER BY device_id"
+        );
+        let rows: Vec<SqliteSensorDataRow> = sqlx::query_as(&sql).fetch_all(&self.pool).await?;
+        Ok(rows
+            .into_iter()
+            .map(SensorData::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::models::InvalidStoredTimestamp;
+    use std::path::PathBuf;
+
+    /// Opens a repository on a fresh database file in the temp directory.
+    async fn setup_test_repository(name: &str) -> (SqliteSensorRepository, PathBuf) {
+        let path = std::env::temp_dir().join(format!("sensor_test_{}.db", name));
+        // テスト前に前回のファイルを削除
+        cleanup(&path);
+
+        let repo = SqliteSensorRepository::open(&path).await.unwrap();
+        repo.migrate().await.expect("Failed to run migrations");
+        (repo, path)
+    }
+
+    fn cleanup(path: &Path) {
+        for suffix in ["", "-wal", "-shm"] {
+            let mut file = path.as_os_str().to_owned();
+            file.push(suffix);
+            std::fs::remove_file(file).ok();
+        }
+    }
+
+    async fn conformance_repository(name: &str) -> SqliteSensorRepository {
+        setup_test_repository(name).await.0
+    }
+
+    crate::persistence::conformance::sensor_repository_conformance_tests!(conformance_repository);
+
+    #[tokio::test]
+    async fn test_opens_database_in_wal_mode() {
+        let (repo, path) = setup_test_repository("wal").await;
+
+        let (mode,): (String,) = sqlx::query_as("PRAGMA journal_mode")
+            .fetch_one(&repo.pool)
+            .await
+            .unwrap();
+        assert_eq!(mode, "wal");
+
+        // クリーンアップ
+        repo.pool.close().await;
+        cleanup(&path);
+    }
+
+    #[tokio::test]
+    async fn test_persists_across_reopen() {
+        let (repo, path) = setup_test_repository("reopen").await;
+
+        let data = SensorData::new("device-002".to_string(), Utc::now());
+        repo.save(&data).await.unwrap();
+        repo.pool.close().await;
+
+        let reopened = SqliteSensorRepository::open(&path).await.unwrap();
+        reopened.migrate().await.unwrap();
+        let results = reopened.find_by_device_id("device-002").await.unwrap();
+        assert_eq!(results.len(), 1);
+
+        // クリーンアップ
+        reopened.pool.close().await;
+        cleanup(&path);
+    }
+
+    #[tokio::test]
+    async fn test_reports_out_of_range_timestamp() {
+        let (repo, path) = setup_test_repository("out_of_range").await;
+
+        // DateTime<Utc> で表現できない値を直接書き込む
+        sqlx::query("INSERT INTO sensor_data (device_id, timestamp) VALUES (?, ?)")
+            .bind("device-003")
+            .bind(i64::MAX)
+            .execute(&repo.pool)
+            .await
+            .unwrap();
+
+        let error = repo.find_by_device_id("device-003").await.unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<InvalidStoredTimestamp>(),
+            Some(&InvalidStoredTimestamp(i64::MAX))
+        );
+        assert!(repo.latest_for_device("device-003").await.is_err());
+
+        // クリーンアップ
+        repo.pool.close().await;
+        cleanup(&path);
+    }
+}
@@ -1,20 +1,31 @@
 use anyhow::Ok;
-use axum::{Router, http, routing::get};
+use server::config::Config;
+use server::state::AppState;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
-
-async fn health_check() -> http::StatusCode {
-    http::StatusCode::OK
-}
+use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let app = Router::new().route("/health", get(health_check));
+    dotenvy::dotenv().ok();
+    // RUST_LOG でログレベルを指定できる（既定は info）
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let config = Config::from_env()?;
+    let repository = server::storage::connect(&config.storage).await?;
+    let state = AppState::new(repository)
+        .with_original_units(config.original_units)
+        .with_validation_policy(config.validation_policy()?);
+    let app = server::router(state);
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
     let listner = TcpListener::bind(addr).await?;
 
-    println!("Listning on: http://{}", addr);
+    tracing::info!("Listning on: http://{}", addr);
     axum::serve(listner, app.into_make_service()).await.unwrap();
 
     Ok(())
@@ -0,0 +1,128 @@
+//! API Error Module
+//!
+//! Maps domain and infrastructure failures onto HTTP responses.
+
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use domain::repositories::InvalidPageToken;
+use domain::sensors::validation::ValidationReport;
+use serde::Serialize;
+
+/// Errors returned by HTTP handlers.
+#[derive(Debug)]
+pub enum ApiError {
+    /// The request itself is malformed (400).
+    BadRequest(String),
+    /// The requested resource does not exist (404).
+    NotFound(String),
+    /// The payload failed domain validation (422).
+    Validation(ValidationReport),
+    /// An unexpected failure, typically from the repository (500).
+    Internal(anyhow::Error),
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl From<ValidationReport> for ApiError {
+    fn from(report: ValidationReport) -> Self {
+        ApiError::Validation(report)
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(error: anyhow::Error) -> Self {
+        if let Some(e) = error.downcast_ref::<InvalidPageToken>() {
+            return ApiError::BadRequest(e.to_string());
+        }
+        ApiError::Internal(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        match self {
+            ApiError::BadRequest(error) => {
+                (StatusCode::BAD_REQUEST, Json(ErrorBody { error })).into_response()
+            }
+            ApiError::NotFound(error) => {
+                (StatusCode::NOT_FOUND, Json(ErrorBody { error })).into_response()
+            }
+            ApiError::Validation(report) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, Json(report)).into_response()
+            }
+            ApiError::Internal(error) => {
+                // 詳細はログにのみ残し、レスポンスには含めない
+                tracing::error!(error = ?error, "internal error");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorBody {
+                        error: "internal server error".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use domain::entities::SensorData;
+    use serde_json::{Value, json};
+
+    #[tokio::test]
+    async fn responds_with_validation_report() {
+        let data = SensorData::new("device-001".to_string(), Utc::now())
+            .with_temperature(20.0, "rankine")
+            .with_co2(60_000.0, "ppm");
+        let response = ApiError::from(ValidationReport::of(&data)).into_response();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "errors": [
+                    {
+                        "path": "temperature.unit",
+                        "message": "invalid unit: rankine",
+                        "code": "invalid_unit",
+                        "unit": "rankine",
+                    },
+                    {
+                        "path": "co2.value",
+                        "message": "value 60000 is out of range [0, 50000]",
+                        "code": "value_out_of_range",
+                        "value": 60_000.0,
+                        "min": 0.0,
+                        "max": 50_000.0,
+                    },
+                ]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn hides_internal_error_details() {
+        let response =
+            ApiError::from(anyhow::anyhow!("connection refused: db.internal:5432")).into_response();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body, json!({ "error": "internal server error" }));
+    }
+}
@@ -0,0 +1,87 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+
+use crate::error::ApiError;
+use crate::models::SensorDataPayload;
+use crate::state::AppState;
+
+/// `GET /devices/latest`
+///
+/// Returns the most recent reading of every device.
+pub async fn latest_for_all_devices(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<SensorDataPayload>>, ApiError> {
+    let latest = state.repository.latest_for_all_devices().await?;
+    Ok(Json(latest.iter().map(SensorDataPayload::from).collect()))
+}
+
+/// `GET /devices/:id/latest`
+///
+/// Returns the most recent reading of one device, or 404 if it has none.
+pub async fn latest_for_device(
+    State(state): State<AppState>,
+    Path(device_id): Path<String>,
+) -> Result<Json<SensorDataPayload>, ApiError> {
+    let latest = state
+        .repository
+        .latest_for_device(&device_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("no readings for device {}", device_id)))?;
+    Ok(Json(SensorDataPayload::from(&latest)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_support::get;
+    use crate::state::AppState;
+    use axum::http::StatusCode;
+    use chrono::{Duration, Utc};
+    use domain::entities::SensorData;
+    use domain::repositories::SensorRepository;
+    use infrastructure::persistence::InMemorySensorRepository;
+    use std::sync::Arc;
+
+    async fn state() -> AppState {
+        let repository = InMemorySensorRepository::new();
+        let now = Utc::now();
+        repository
+            .save_many(&[
+                SensorData::new("device-b".to_string(), now).with_co2(402.0, "ppm"),
+                SensorData::new("device-a".to_string(), now - Duration::minutes(1))
+                    .with_co2(501.0, "ppm"),
+                SensorData::new("device-b".to_string(), now - Duration::minutes(2))
+                    .with_co2(401.0, "ppm"),
+            ])
+            .await
+            .unwrap();
+        AppState::new(Arc::new(repository))
+    }
+
+    #[tokio::test]
+    async fn returns_latest_reading_of_every_device() {
+        let (status, body) = get(state().await, "/devices/latest").await;
+
+        assert_eq!(status, StatusCode::OK);
+        let items = body.as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["device_id"], "device-a");
+        assert_eq!(items[1]["co2"]["value"], 402.0);
+    }
+
+    #[tokio::test]
+    async fn returns_latest_reading_of_one_device() {
+        let (status, body) = get(state().await, "/devices/device-b/latest").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["co2"]["value"], 402.0);
+    }
+
+    #[tokio::test]
+    async fn returns_404_for_unknown_device() {
+        let (status, _) = get(state().await, "/devices/unknown/latest").await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
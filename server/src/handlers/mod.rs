@@ -0,0 +1,9 @@
+pub mod health;
+pub mod latest;
+pub mod readings;
+pub mod sensor_data;
+pub mod sensor_ingest;
+pub mod sensor_stream;
+
+#[cfg(test)]
+pub(crate) mod test_support;
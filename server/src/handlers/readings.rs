@@ -0,0 +1,212 @@
+use axum::{
+    Json,
+    body::Body,
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use domain::repositories::{PageRequest, PageToken, SortOrder};
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApiError;
+use crate::models::SensorDataPayload;
+use crate::state::AppState;
+
+/// Page size used when the client does not specify one.
+const DEFAULT_LIMIT: u32 = 100;
+/// Largest page size a client may request.
+const MAX_LIMIT: u32 = 1000;
+
+/// Sort order accepted in query strings.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderParam {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl From<OrderParam> for SortOrder {
+    fn from(order: OrderParam) -> Self {
+        match order {
+            OrderParam::Asc => SortOrder::Ascending,
+            OrderParam::Desc => SortOrder::Descending,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReadingsQuery {
+    pub limit: Option<u32>,
+    #[serde(default)]
+    pub order: OrderParam,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReadingsPage {
+    pub items: Vec<SensorDataPayload>,
+    pub next_cursor: Option<String>,
+}
+
+/// `GET /devices/:id/readings`
+///
+/// Returns one page of a device's history, newest first by default.
+/// Pass `next_cursor` back as `cursor` to fetch the following page.
+pub async fn list_readings(
+    State(state): State<AppState>,
+    Path(device_id): Path<String>,
+    Query(query): Query<ReadingsQuery>,
+) -> Result<Json<ReadingsPage>, ApiError> {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+    if !(1..=MAX_LIMIT).contains(&limit) {
+        return Err(ApiError::BadRequest(format!(
+            "limit must be between 1 and {}",
+            MAX_LIMIT
+        )));
+    }
+
+    let mut request = PageRequest::new(limit).with_order(query.order.into());
+    if let Some(cursor) = &query.cursor {
+        let token = cursor
+            .parse::<PageToken>()
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        request = request.with_after(token);
+    }
+
+    let page = state
+        .repository
+        .find_page_by_device_id(&device_id, request)
+        .await?;
+
+    Ok(Json(ReadingsPage {
+        items: page.items.iter().map(SensorDataPayload::from).collect(),
+        next_cursor: page.next.map(|token| token.to_string()),
+    }))
+}
+
+/// `GET /devices/:id/readings/export`
+///
+/// Streams a device's whole history as newline-delimited JSON, oldest
+/// first, straight from the repository cursor.
+pub async fn export_readings(
+    State(state): State<AppState>,
+    Path(device_id): Path<String>,
+) -> Result<Response, ApiError> {
+    let stream = state.repository.stream_by_device_id(&device_id).await?;
+    let lines = stream.and_then(|data| async move {
+        let mut line = serde_json::to_vec(&SensorDataPayload::from(&data))?;
+        line.push(b'\n');
+        Ok(line)
+    });
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(lines),
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::test_support::get;
+    use axum::{http::Request, http::StatusCode};
+    use chrono::{Duration, Utc};
+    use domain::entities::SensorData;
+    use domain::repositories::SensorRepository;
+    use infrastructure::persistence::InMemorySensorRepository;
+    use serde_json::Value;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn state_with_readings(count: i64) -> AppState {
+        let repository = InMemorySensorRepository::new();
+        let now = Utc::now();
+        let mut data: Vec<SensorData> = (0..count)
+            .map(|i| SensorData::new("device-001".to_string(), now - Duration::minutes(i)))
+            .collect();
+        data.push(SensorData::new("device-002".to_string(), now));
+        repository.save_many(&data).await.unwrap();
+        AppState::new(Arc::new(repository))
+    }
+
+    #[tokio::test]
+    async fn pages_through_device_history() {
+        let state = state_with_readings(5).await;
+
+        let (status, first) = get(state.clone(), "/devices/device-001/readings?limit=3").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["items"].as_array().unwrap().len(), 3);
+        let cursor = first["next_cursor"].as_str().unwrap();
+
+        let (_, second) = get(
+            state,
+            &format!("/devices/device-001/readings?limit=3&cursor={}", cursor),
+        )
+        .await;
+        assert_eq!(second["items"].as_array().unwrap().len(), 2);
+        assert!(second["next_cursor"].is_null());
+    }
+
+    #[tokio::test]
+    async fn returns_newest_first_by_default() {
+        let state = state_with_readings(3).await;
+
+        let (_, body) = get(state, "/devices/device-001/readings").await;
+
+        let items = body["items"].as_array().unwrap();
+        assert!(items[0]["timestamp"].as_str() > items[1]["timestamp"].as_str());
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_cursor() {
+        let state = state_with_readings(1).await;
+
+        let (status, _) = get(state, "/devices/device-001/readings?cursor=garbage").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn rejects_out_of_range_limit() {
+        let state = state_with_readings(1).await;
+
+        let (status, _) = get(state, "/devices/device-001/readings?limit=0").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn exports_history_as_ndjson() {
+        let state = state_with_readings(3).await;
+        let app = crate::router(state);
+
+        let response = app
+            .oneshot(
+                Request::get("/devices/device-001/readings/export")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/x-ndjson"
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let lines: Vec<Value> = std::str::from_utf8(&bytes)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|v| v["device_id"] == "device-001"));
+        assert!(lines[0]["timestamp"].as_str() < lines[1]["timestamp"].as_str());
+    }
+}
@@ -0,0 +1,413 @@
+use axum::{Json, extract::State, http::StatusCode};
+use domain::entities::SensorData;
+use domain::repositories::SaveOutcome;
+use domain::sensors::validation::ValidationReport;
+use serde::Serialize;
+
+use crate::error::ApiError;
+use crate::ingestion::{ingest, ingest_many};
+use crate::models::SensorDataPayload;
+use crate::state::AppState;
+
+/// Largest number of readings accepted in one batch request.
+const MAX_BATCH_SIZE: usize = 1000;
+
+/// Outcome of a single reading within a batch.
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BatchItemResult {
+    Accepted {
+        index: usize,
+    },
+    Duplicate {
+        index: usize,
+    },
+    Rejected {
+        index: usize,
+        #[serde(flatten)]
+        report: ValidationReport,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchResponse {
+    pub accepted: usize,
+    pub duplicate: usize,
+    pub rejected: usize,
+    pub results: Vec<BatchItemResult>,
+}
+
+/// `POST /sensor-data`
+///
+/// Validates the reading, persists it in canonical units through the
+/// repository and publishes it to live subscribers. Responds with the
+/// reading as stored. Responds with 201 for a new reading and 200 when
+/// the same device and timestamp was already stored.
+pub async fn create_sensor_data(
+    State(state): State<AppState>,
+    Json(payload): Json<SensorDataPayload>,
+) -> Result<(StatusCode, Json<SensorDataPayload>), ApiError> {
+    let data = SensorData::from(payload);
+    let (stored, outcome) = ingest(&state, &data).await?;
+    let status = match outcome {
+        SaveOutcome::Inserted => StatusCode::CREATED,
+        SaveOutcome::Duplicate => StatusCode::OK,
+    };
+
+    Ok((status, Json(SensorDataPayload::from(&stored))))
+}
+
+/// `POST /sensor-data/batch`
+///
+/// Accepts an array of readings, saves every valid one in a single
+/// repository call and reports the outcome of each item by index.
+pub async fn create_sensor_data_batch(
+    State(state): State<AppState>,
+    Json(payloads): Json<Vec<SensorDataPayload>>,
+) -> Result<Json<BatchResponse>, ApiError> {
+    if payloads.len() > MAX_BATCH_SIZE {
+        return Err(ApiError::BadRequest(format!(
+            "batch must not contain more than {} readings",
+            MAX_BATCH_SIZE
+        )));
+    }
+
+    let batch = payloads.into_iter().map(SensorData::from).collect();
+    let results: Vec<BatchItemResult> = ingest_many(&state, batch)
+        .await?
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| match result {
+            Ok(SaveOutcome::Inserted) => BatchItemResult::Accepted { index },
+            Ok(SaveOutcome::Duplicate) => BatchItemResult::Duplicate { index },
+            Err(report) => BatchItemResult::Rejected { index, report },
+        })
+        .collect();
+
+    let count = |f: fn(&BatchItemResult) -> bool| results.iter().filter(|r| f(r)).count();
+    Ok(Json(BatchResponse {
+        accepted: count(|r| matches!(r, BatchItemResult::Accepted { .. })),
+        duplicate: count(|r| matches!(r, BatchItemResult::Duplicate { .. })),
+        rejected: count(|r| matches!(r, BatchItemResult::Rejected { .. })),
+        results,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::test_support::post_json;
+    use chrono::Utc;
+    use domain::clock::FixedClock;
+    use domain::repositories::SensorRepository;
+    use domain::sensors::normalization::OriginalUnits;
+    use domain::sensors::policy::ValidationPolicy;
+    use infrastructure::persistence::InMemorySensorRepository;
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+
+    async fn post(state: AppState, body: Value) -> (StatusCode, Value) {
+        post_json(state, "/sensor-data", &body).await
+    }
+
+    #[tokio::test]
+    async fn saves_valid_sensor_data() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+
+        let (status, body) = post(
+            AppState::new(repository.clone()),
+            json!({
+                "device_id": "device-001",
+                "timestamp": Utc::now(),
+                "temperature": { "value": 22.5, "unit": "celsius" },
+                "pressure": { "value": 1013.25, "unit": "hPa" },
+                "pm2_5": { "value": 12.5, "unit": "ug/m3" },
+                "voc": { "value": 250.0, "unit": "ppb" },
+                "illuminance": { "value": 500.0, "unit": "lx" },
+                "sound_level": { "value": 42.5, "unit": "dBA" },
+                "additional_sensors": { "wind_speed": { "value": 3.5, "unit": "m/s" } },
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["device_id"], "device-001");
+
+        let saved = repository.find_by_device_id("device-001").await.unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].pressure.as_ref().unwrap().value, 1013.25);
+        assert_eq!(saved[0].pm2_5.as_ref().unwrap().unit, "µg/m³");
+        assert_eq!(saved[0].illuminance.as_ref().unwrap().unit, "lux");
+        assert_eq!(saved[0].sound_level.as_ref().unwrap().value, 42.5);
+        assert!(saved[0].additional_sensors.contains_key("wind_speed"));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_sensor_data_with_422() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+
+        let (status, body) = post(
+            AppState::new(repository.clone()),
+            json!({
+                "device_id": "device-001",
+                "timestamp": Utc::now(),
+                "temperature": { "value": 500.0, "unit": "celsius" },
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["path"], "temperature.value");
+        assert_eq!(body["errors"][0]["code"], "value_out_of_range");
+
+        // 保存されていないことを確認
+        assert!(repository.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_every_validation_error() {
+        let (status, body) = post(
+            AppState::new(Arc::new(InMemorySensorRepository::new())),
+            json!({
+                "device_id": "",
+                "timestamp": Utc::now(),
+                "humidity": { "value": 50.0, "unit": "ratio" },
+                "co2": { "value": 60_000.0, "unit": "ppm" },
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let paths: Vec<&str> = body["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["path"].as_str().unwrap())
+            .collect();
+        assert_eq!(paths, ["device_id", "humidity.unit", "co2.value"]);
+    }
+
+    #[tokio::test]
+    async fn publishes_saved_sensor_data() {
+        let state = AppState::new(Arc::new(InMemorySensorRepository::new()));
+        let mut events = state.subscribe();
+
+        let (status, _) = post(
+            state,
+            json!({
+                "device_id": "device-001",
+                "timestamp": Utc::now(),
+                "humidity": { "value": 40.0, "unit": "percent" },
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        let published = events.try_recv().unwrap();
+        assert_eq!(published.device_id, "device-001");
+    }
+
+    #[tokio::test]
+    async fn does_not_publish_invalid_sensor_data() {
+        let state = AppState::new(Arc::new(InMemorySensorRepository::new()));
+        let mut events = state.subscribe();
+
+        let (status, _) = post(state, json!({ "device_id": "", "timestamp": Utc::now() })).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn saves_valid_items_of_partially_invalid_batch() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+        let state = AppState::new(repository.clone());
+        let mut events = state.subscribe();
+
+        let body = json!([
+            { "device_id": "device-001", "timestamp": Utc::now(), "co2": { "value": 410.0, "unit": "ppm" } },
+            { "device_id": "device-001", "timestamp": Utc::now(), "co2": { "value": -5.0, "unit": "ppm" } },
+            { "device_id": "device-002", "timestamp": Utc::now() },
+        ]);
+        let (status, body) = post_json(state, "/sensor-data/batch", &body).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["accepted"], 2);
+        assert_eq!(body["duplicate"], 0);
+        assert_eq!(body["rejected"], 1);
+        assert_eq!(body["results"][0]["status"], "accepted");
+        assert_eq!(body["results"][1]["status"], "rejected");
+        assert_eq!(body["results"][1]["index"], 1);
+        assert_eq!(
+            body["results"][1]["errors"][0]["code"],
+            "value_out_of_range"
+        );
+
+        assert_eq!(repository.len(), 2);
+        assert!(events.try_recv().is_ok());
+        assert!(events.try_recv().is_ok());
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn reports_duplicate_without_publishing() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+        let state = AppState::new(repository.clone());
+        let body = json!({
+            "device_id": "device-001",
+            "timestamp": Utc::now(),
+            "co2": { "value": 410.0, "unit": "ppm" },
+        });
+
+        let (status, _) = post(state.clone(), body.clone()).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let mut events = state.subscribe();
+        let (status, _) = post(state, body).await;
+        assert_eq!(status, StatusCode::OK);
+
+        assert_eq!(repository.len(), 1);
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn stores_measurements_in_canonical_units() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+
+        let (status, body) = post(
+            AppState::new(repository.clone()),
+            json!({
+                "device_id": "device-001",
+                "timestamp": Utc::now(),
+                "temperature": { "value": 77.0, "unit": "F" },
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["temperature"]["value"], 25.0);
+        assert_eq!(body["temperature"]["unit"], "Celsius");
+        assert!(body["temperature"].get("original").is_none());
+
+        let saved = repository.find_by_device_id("device-001").await.unwrap();
+        let temperature = saved[0].temperature.as_ref().unwrap();
+        assert_eq!(temperature.unit, "Celsius");
+        assert_eq!(temperature.original, None);
+    }
+
+    #[tokio::test]
+    async fn keeps_original_units_when_enabled() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+
+        let (status, body) = post(
+            AppState::new(repository.clone()).with_original_units(OriginalUnits::Keep),
+            json!({
+                "device_id": "device-001",
+                "timestamp": Utc::now(),
+                "temperature": { "value": 300.0, "unit": "kelvin" },
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["temperature"]["unit"], "Celsius");
+        assert_eq!(body["temperature"]["original"]["value"], 300.0);
+        assert_eq!(body["temperature"]["original"]["unit"], "kelvin");
+
+        let saved = repository.find_by_device_id("device-001").await.unwrap();
+        let original = saved[0].temperature.as_ref().unwrap().original.as_ref();
+        assert_eq!(original.unwrap().unit, "kelvin");
+    }
+
+    #[tokio::test]
+    async fn validates_against_device_policy() {
+        let policy: ValidationPolicy = serde_json::from_value(json!({
+            "devices": { "freezer-01": { "temperature": { "min": -80.0, "max": 0.0 } } },
+        }))
+        .unwrap();
+        let state =
+            AppState::new(Arc::new(InMemorySensorRepository::new())).with_validation_policy(policy);
+        let reading = |device_id: &str| {
+            json!({
+                "device_id": device_id,
+                "timestamp": Utc::now(),
+                "temperature": { "value": -70.0, "unit": "celsius" },
+            })
+        };
+
+        let (status, _) = post(state.clone(), reading("freezer-01")).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = post(state, reading("device-001")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["min"], -50.0);
+    }
+
+    #[tokio::test]
+    async fn validates_additional_sensors_against_registry() {
+        let policy: ValidationPolicy = serde_json::from_value(json!({
+            "additional_sensors": {
+                "unknown": "reject",
+                "kinds": {
+                    "wind_speed": {
+                        "unit": "m/s",
+                        "range": { "min": 0.0, "max": 100.0 },
+                        "units": { "cm/s": 0.01 },
+                    },
+                },
+            },
+        }))
+        .unwrap();
+        let repository = Arc::new(InMemorySensorRepository::new());
+        let state = AppState::new(repository.clone()).with_validation_policy(policy);
+        let reading = |name: &str| {
+            json!({
+                "device_id": "device-001",
+                "timestamp": Utc::now(),
+                "additional_sensors": { name: { "value": 350.0, "unit": "cm/s" } },
+            })
+        };
+
+        let (status, body) = post(state.clone(), reading("wind_speed")).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["additional_sensors"]["wind_speed"]["value"], 3.5);
+        assert_eq!(body["additional_sensors"]["wind_speed"]["unit"], "m/s");
+
+        let (status, body) = post(state, reading("gust_speed")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["code"], "unknown_kind");
+        assert_eq!(body["errors"][0]["path"], "additional_sensors.gust_speed");
+        assert_eq!(
+            repository
+                .find_by_device_id("device-001")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn validates_timestamp_against_state_clock() {
+        let now = Utc::now() - chrono::Duration::days(1);
+        let mut policy = ValidationPolicy::default();
+        policy.timestamps.future_tolerance_secs = 5;
+        let state = AppState::new(Arc::new(InMemorySensorRepository::new()))
+            .with_validation_policy(policy)
+            .with_clock(Arc::new(FixedClock::new(now)));
+        let reading = |timestamp: chrono::DateTime<Utc>| {
+            json!({
+                "device_id": "device-001",
+                "timestamp": timestamp,
+                "co2": { "value": 410.0, "unit": "ppm" },
+            })
+        };
+
+        let (status, _) = post(state.clone(), reading(now + chrono::Duration::seconds(3))).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = post(state, reading(now + chrono::Duration::seconds(10))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["code"], "future_timestamp");
+    }
+}
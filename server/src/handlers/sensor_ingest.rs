@@ -0,0 +1,223 @@
+use axum::{
+    extract::{
+        State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+};
+use domain::entities::SensorData;
+use domain::repositories::SaveOutcome;
+use domain::sensors::validation::ValidationReport;
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApiError;
+use crate::ingestion::ingest;
+use crate::models::SensorDataPayload;
+use crate::state::AppState;
+
+/// A reading pushed by a device, tagged with a client-chosen id that is
+/// echoed back in the reply.
+#[derive(Debug, Deserialize)]
+pub struct IngestFrame {
+    pub message_id: String,
+    #[serde(flatten)]
+    pub data: SensorDataPayload,
+}
+
+/// Reply sent for every frame received on the ingestion socket.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IngestReply {
+    /// The reading was validated and saved. `duplicate` is set when the same
+    /// device and timestamp had already been stored.
+    Ack { message_id: String, duplicate: bool },
+    /// The reading failed domain validation and was not saved. Every
+    /// problem found is listed.
+    ValidationError {
+        message_id: String,
+        #[serde(flatten)]
+        report: ValidationReport,
+    },
+    /// The frame could not be parsed or the reading could not be saved.
+    Error {
+        message_id: Option<String>,
+        message: String,
+    },
+}
+
+/// `GET /ws/sensor-ingest`
+///
+/// Device-facing socket accepting one reading per text frame. Each frame is
+/// answered, in order, with an ack or an error carrying its `message_id`.
+pub async fn sensor_ingest(ws: WebSocketUpgrade, State(state): State<AppState>) -> Response {
+    ws.on_upgrade(move |socket| ingest_sensor_data(socket, state))
+}
+
+async fn ingest_sensor_data(socket: WebSocket, state: AppState) {
+    let (mut sender, mut receiver) = socket.split();
+
+    while let Some(message) = receiver.next().await {
+        let reply = match message {
+            Ok(Message::Text(text)) => handle_frame(&state, &text).await,
+            Ok(Message::Close(_)) | Err(_) => break,
+            Ok(_) => continue,
+        };
+
+        let Ok(text) = serde_json::to_string(&reply) else {
+            continue;
+        };
+        if sender.send(Message::Text(text)).await.is_err() {
+            break;
+        }
+    }
+}
+
+async fn handle_frame(state: &AppState, text: &str) -> IngestReply {
+    let frame = match serde_json::from_str::<IngestFrame>(text) {
+        Ok(frame) => frame,
+        Err(e) => {
+            // 解析できなくても message_id だけは返せるようにする
+            let message_id = serde_json::from_str::<serde_json::Value>(text)
+                .ok()
+                .and_then(|v| v.get("message_id")?.as_str().map(str::to_string));
+            return IngestReply::Error {
+                message_id,
+                message: e.to_string(),
+            };
+        }
+    };
+
+    let message_id = frame.message_id;
+    let data = SensorData::from(frame.data);
+    match ingest(state, &data).await {
+        Ok((_, outcome)) => IngestReply::Ack {
+            message_id,
+            duplicate: outcome == SaveOutcome::Duplicate,
+        },
+        Err(ApiError::Validation(report)) => IngestReply::ValidationError { message_id, report },
+        Err(ApiError::BadRequest(message)) | Err(ApiError::NotFound(message)) => {
+            IngestReply::Error {
+                message_id: Some(message_id),
+                message,
+            }
+        }
+        Err(ApiError::Internal(error)) => {
+            tracing::error!(error = ?error, message_id = %message_id, "failed to ingest frame");
+            IngestReply::Error {
+                message_id: Some(message_id),
+                message: "internal server error".to_string(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::test_support::{next_json, spawn_ws_server};
+    use chrono::Utc;
+    use infrastructure::persistence::InMemorySensorRepository;
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+    use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite};
+
+    type Client = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+    async fn connect(state: AppState) -> Client {
+        let url = spawn_ws_server(state, "/ws/sensor-ingest").await;
+        let (stream, _) = connect_async(url).await.unwrap();
+        stream
+    }
+
+    async fn request(client: &mut Client, frame: Value) -> Value {
+        client
+            .send(tungstenite::Message::Text(frame.to_string()))
+            .await
+            .unwrap();
+        next_json(client).await
+    }
+
+    #[tokio::test]
+    async fn acks_and_saves_valid_frame() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+        let mut client = connect(AppState::new(repository.clone())).await;
+
+        let reply = request(
+            &mut client,
+            json!({
+                "message_id": "m-1",
+                "device_id": "device-001",
+                "timestamp": Utc::now(),
+                "co2": { "value": 420.0, "unit": "ppm" },
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            reply,
+            json!({ "type": "ack", "message_id": "m-1", "duplicate": false })
+        );
+        assert_eq!(repository.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn replies_with_typed_validation_error() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+        let mut client = connect(AppState::new(repository.clone())).await;
+
+        let reply = request(
+            &mut client,
+            json!({
+                "message_id": "m-2",
+                "device_id": "device-001",
+                "timestamp": Utc::now(),
+                "temperature": { "value": 20.0, "unit": "rankine" },
+            }),
+        )
+        .await;
+
+        assert_eq!(reply["type"], "validation_error");
+        assert_eq!(reply["message_id"], "m-2");
+        assert_eq!(reply["errors"][0]["path"], "temperature.unit");
+        assert_eq!(reply["errors"][0]["code"], "invalid_unit");
+        assert!(repository.is_empty());
+    }
+
+    #[tokio::test]
+    async fn replies_with_error_to_malformed_frame() {
+        let mut client = connect(AppState::new(Arc::new(InMemorySensorRepository::new()))).await;
+
+        let reply = request(&mut client, json!({ "message_id": "m-3", "device_id": 1 })).await;
+
+        assert_eq!(reply["type"], "error");
+        assert_eq!(reply["message_id"], "m-3");
+
+        // エラー後も接続は維持される
+        let reply = request(
+            &mut client,
+            json!({ "message_id": "m-4", "device_id": "device-001", "timestamp": Utc::now() }),
+        )
+        .await;
+        assert_eq!(reply["type"], "ack");
+    }
+
+    #[tokio::test]
+    async fn acks_retried_frame_as_duplicate() {
+        let repository = Arc::new(InMemorySensorRepository::new());
+        let mut client = connect(AppState::new(repository.clone())).await;
+        let frame = json!({
+            "message_id": "m-5",
+            "device_id": "device-001",
+            "timestamp": Utc::now(),
+        });
+
+        let first = request(&mut client, frame.clone()).await;
+        let retry = request(&mut client, frame).await;
+
+        assert_eq!(first["duplicate"], false);
+        assert_eq!(retry["type"], "ack");
+        assert_eq!(retry["duplicate"], true);
+        assert_eq!(repository.len(), 1);
+    }
+}
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{
+        State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+};
+use domain::entities::SensorData;
+use futures::{SinkExt, StreamExt};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::models::SensorDataPayload;
+use crate::state::AppState;
+use crate::subscription::{SubscriptionFilter, SubscriptionRequest, SubscriptionResponse};
+
+/// Maximum time a single frame may take to reach a client before the
+/// connection is considered stuck and dropped.
+const SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// `GET /ws/sensor-data`
+///
+/// Streams newly saved readings to the client as JSON text frames.
+///
+/// Clients may send `subscribe`, `unsubscribe` and `reset` messages at any
+/// time to restrict the stream to certain devices and measurements; each one
+/// is answered with the filter now in effect. A client that falls behind
+/// receives a `lagged` message with the number of readings it missed.
+pub async fn sensor_stream(ws: WebSocketUpgrade, State(state): State<AppState>) -> Response {
+    // 接続確立前に購読し、アップグレード直後のデータを取りこぼさないようにする
+    let events = state.subscribe();
+    ws.on_upgrade(move |socket| stream_sensor_data(socket, events))
+}
+
+async fn stream_sensor_data(socket: WebSocket, mut events: broadcast::Receiver<SensorData>) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut filter = SubscriptionFilter::default();
+
+    loop {
+        let text = tokio::select! {
+            event = events.recv() => match event {
+                Ok(data) => {
+                    let Some(data) = filter.filter(&data) else {
+                        continue;
+                    };
+                    match serde_json::to_string(&SensorDataPayload::from(&data)) {
+                        Ok(text) => text,
+                        Err(e) => {
+                            tracing::error!(
+                                error = %e,
+                                device_id = %data.device_id,
+                                "failed to serialize sensor data"
+                            );
+                            continue;
+                        }
+                    }
+                }
+                // 取りこぼした分は送らず、件数を通知して最新のデータから配信を続ける
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "live stream client lagged");
+                    match serde_json::to_string(&SubscriptionResponse::Lagged { skipped }) {
+                        Ok(text) => text,
+                        Err(e) => {
+                            tracing::error!(error = %e, "failed to serialize lag notice");
+                            continue;
+                        }
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            },
+            message = receiver.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let response = match serde_json::from_str::<SubscriptionRequest>(&text) {
+                        Ok(request) => {
+                            filter.apply(request);
+                            filter.to_response()
+                        }
+                        Err(e) => SubscriptionResponse::Error {
+                            message: e.to_string(),
+                        },
+                    };
+                    match serde_json::to_string(&response) {
+                        Ok(text) => text,
+                        Err(e) => {
+                            tracing::error!(
+                                error = %e,
+                                "failed to serialize subscription response"
+                            );
+                            continue;
+                        }
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+        };
+
+        match tokio::time::timeout(SEND_TIMEOUT, sender.send(Message::Text(text))).await {
+            Ok(Ok(())) => {}
+            Ok(Err(_)) | Err(_) => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::test_support::{next_json, spawn_ws_server};
+    use chrono::Utc;
+    use infrastructure::persistence::InMemorySensorRepository;
+    use std::sync::Arc;
+    use tokio_tungstenite::{connect_async, tungstenite};
+
+    #[tokio::test]
+    async fn pushes_published_readings_to_client() {
+        let state = AppState::new(Arc::new(InMemorySensorRepository::new()));
+        let url = spawn_ws_server(state.clone(), "/ws/sensor-data").await;
+
+        let (mut stream, _) = connect_async(url).await.unwrap();
+
+        state
+            .publish(&SensorData::new("device-001".to_string(), Utc::now()).with_co2(420.0, "ppm"));
+
+        let value = next_json(&mut stream).await;
+        assert_eq!(value["device_id"], "device-001");
+        assert_eq!(value["co2"]["value"], 420.0);
+    }
+
+    #[tokio::test]
+    async fn reports_lagged_readings_and_keeps_streaming() {
+        let state = AppState::new(Arc::new(InMemorySensorRepository::new()));
+        let url = spawn_ws_server(state.clone(), "/ws/sensor-data").await;
+
+        let (mut stream, _) = connect_async(url).await.unwrap();
+
+        // バッファを溢れさせても接続は維持される
+        for i in 0..5_000 {
+            state.publish(&SensorData::new(format!("device-{}", i), Utc::now()));
+        }
+        state.publish(&SensorData::new("last".to_string(), Utc::now()));
+
+        let mut skipped = 0;
+        loop {
+            let value = next_json(&mut stream).await;
+            if value["type"] == "lagged" {
+                skipped += value["skipped"].as_u64().unwrap();
+            }
+            if value["device_id"] == "last" {
+                break;
+            }
+        }
+        assert!(skipped > 0);
+    }
+
+    #[tokio::test]
+    async fn applies_subscription_filter_mid_connection() {
+        let state = AppState::new(Arc::new(InMemorySensorRepository::new()));
+        let url = spawn_ws_server(state.clone(), "/ws/sensor-data").await;
+
+        let (mut stream, _) = connect_async(url).await.unwrap();
+
+        stream
+            .send(tungstenite::Message::Text(
+                r#"{"type":"subscribe","device_ids":["device-002"],"measurements":["co2"]}"#
+                    .to_string(),
+            ))
+            .await
+            .unwrap();
+        let ack = next_json(&mut stream).await;
+        assert_eq!(ack["type"], "subscription");
+        assert_eq!(ack["device_ids"], serde_json::json!(["device-002"]));
+
+        let reading = |device_id: &str| {
+            SensorData::new(device_id.to_string(), Utc::now())
+                .with_temperature(21.0, "celsius")
+                .with_co2(420.0, "ppm")
+        };
+        state.publish(&reading("device-001"));
+        state.publish(&reading("device-002"));
+
+        let value = next_json(&mut stream).await;
+        assert_eq!(value["device_id"], "device-002");
+        assert!(value.get("temperature").is_none());
+        assert_eq!(value["co2"]["value"], 420.0);
+
+        // 購読解除後は全デバイスのデータを受け取る
+        stream
+            .send(tungstenite::Message::Text(
+                r#"{"type":"reset"}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        let ack = next_json(&mut stream).await;
+        assert!(ack["device_ids"].is_null());
+
+        state.publish(&reading("device-001"));
+        let value = next_json(&mut stream).await;
+        assert_eq!(value["device_id"], "device-001");
+    }
+
+    #[tokio::test]
+    async fn replies_with_error_to_malformed_request() {
+        let state = AppState::new(Arc::new(InMemorySensorRepository::new()));
+        let url = spawn_ws_server(state.clone(), "/ws/sensor-data").await;
+
+        let (mut stream, _) = connect_async(url).await.unwrap();
+        stream
+            .send(tungstenite::Message::Text(
+                r#"{"type":"bogus"}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let value = next_json(&mut stream).await;
+        assert_eq!(value["type"], "error");
+    }
+}
@@ -0,0 +1,67 @@
+//! Request helpers shared by the handler tests.
+
+use std::time::Duration;
+
+use axum::{body::Body, http::Request, http::StatusCode};
+use futures::StreamExt;
+use serde_json::Value;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite;
+use tower::ServiceExt;
+
+use crate::state::AppState;
+
+/// Sends a request through the application router and returns the response
+/// status with its body parsed as JSON, or `Value::Null` when it is not JSON.
+pub(crate) async fn send(state: AppState, request: Request<Body>) -> (StatusCode, Value) {
+    let app = crate::router(state);
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+/// Sends a GET request for `uri`.
+pub(crate) async fn get(state: AppState, uri: &str) -> (StatusCode, Value) {
+    send(state, Request::get(uri).body(Body::empty()).unwrap()).await
+}
+
+/// Sends a POST request to `uri` with `body` as its JSON payload.
+pub(crate) async fn post_json(state: AppState, uri: &str, body: &Value) -> (StatusCode, Value) {
+    let request = Request::post(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    send(state, request).await
+}
+
+/// Serves the application router on a free local port and returns the
+/// WebSocket URL of `path` on it.
+pub(crate) async fn spawn_ws_server(state: AppState, path: &str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = crate::router(state);
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    format!("ws://{}{}", addr, path)
+}
+
+/// Waits for the next WebSocket frame and parses it as JSON.
+pub(crate) async fn next_json(
+    stream: &mut (impl StreamExt<Item = Result<tungstenite::Message, tungstenite::Error>> + Unpin),
+) -> Value {
+    let message = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("timed out waiting for message")
+        .unwrap()
+        .unwrap();
+    serde_json::from_str(message.to_text().unwrap()).unwrap()
+}
@@ -0,0 +1,75 @@
+//! Ingestion Module
+//!
+//! Provides the single path every incoming reading takes, regardless of
+//! the transport it arrived on.
+
+use domain::entities::SensorData;
+use domain::repositories::SaveOutcome;
+use domain::sensors::normalization::normalize;
+use domain::sensors::validation::ValidationReport;
+
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// Validates a reading, normalizes it to canonical units, saves it and
+/// publishes it to live subscribers.
+///
+/// Returns the reading as stored. Duplicates of an already stored reading
+/// are reported but not published.
+pub async fn ingest(
+    state: &AppState,
+    data: &SensorData,
+) -> Result<(SensorData, SaveOutcome), ApiError> {
+    let data = normalize(
+        data,
+        &state.validation_policy,
+        state.clock.as_ref(),
+        state.original_units,
+    )?;
+
+    let outcome = state.repository.save(&data).await?;
+    if outcome == SaveOutcome::Inserted {
+        state.publish(&data);
+    }
+
+    Ok((data, outcome))
+}
+
+/// Validates and normalizes a batch of readings, saves the valid ones in a
+/// single call and publishes them.
+///
+/// Returns the outcome of every reading, in input order. Invalid readings
+/// are skipped without affecting the rest of the batch.
+pub async fn ingest_many(
+    state: &AppState,
+    batch: Vec<SensorData>,
+) -> Result<Vec<Result<SaveOutcome, ValidationReport>>, ApiError> {
+    let mut results = Vec::with_capacity(batch.len());
+    let mut valid = Vec::with_capacity(batch.len());
+    let mut valid_indices = Vec::with_capacity(batch.len());
+    for (index, data) in batch.into_iter().enumerate() {
+        match normalize(
+            &data,
+            &state.validation_policy,
+            state.clock.as_ref(),
+            state.original_units,
+        ) {
+            Ok(data) => {
+                valid_indices.push(index);
+                valid.push(data);
+                results.push(Ok(SaveOutcome::Inserted));
+            }
+            Err(report) => results.push(Err(report)),
+        }
+    }
+
+    let outcomes = state.repository.save_many(&valid).await?;
+    for ((index, data), outcome) in valid_indices.into_iter().zip(&valid).zip(outcomes) {
+        if outcome == SaveOutcome::Inserted {
+            state.publish(data);
+        }
+        results[index] = Ok(outcome);
+    }
+
+    Ok(results)
+}
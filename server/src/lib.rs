@@ -0,0 +1,54 @@
+pub mod config;
+pub mod error;
+pub mod handlers;
+pub mod ingestion;
+pub mod models;
+pub mod state;
+pub mod storage;
+pub mod subscription;
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::state::AppState;
+
+/// Builds the application router with all routes attached to the given state.
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/health", get(handlers::health::health_check))
+        .route(
+            "/sensor-data",
+            post(handlers::sensor_data::create_sensor_data),
+        )
+        .route(
+            "/sensor-data/batch",
+            post(handlers::sensor_data::create_sensor_data_batch),
+        )
+        .route(
+            "/devices/latest",
+            get(handlers::latest::latest_for_all_devices),
+        )
+        .route(
+            "/devices/:id/latest",
+            get(handlers::latest::latest_for_device),
+        )
+        .route(
+            "/devices/:id/readings",
+            get(handlers::readings::list_readings),
+        )
+        .route(
+            "/devices/:id/readings/export",
+            get(handlers::readings::export_readings),
+        )
+        .route(
+            "/ws/sensor-data",
+            get(handlers::sensor_stream::sensor_stream),
+        )
+        .route(
+            "/ws/sensor-ingest",
+            get(handlers::sensor_ingest::sensor_ingest),
+        )
+        .with_state(state)
+}
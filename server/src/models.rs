@@ -0,0 +1,141 @@
+//! API Models Module
+//!
+//! Provides the JSON representations exchanged with clients.
+
+use chrono::{DateTime, Utc};
+use domain::entities::{
+    OriginalMeasurement as DomainOriginal, SensorData, SensorMeasurement as DomainMeasurement,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SensorDataPayload {
+    pub device_id: String,
+
+    pub timestamp: DateTime<Utc>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub humidity: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub co2: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pressure: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm1_0: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm2_5: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm10: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voc: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub illuminance: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound_level: Option<SensorMeasurement>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub additional_sensors: HashMap<String, SensorMeasurement>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SensorMeasurement {
+    pub value: f64,
+    pub unit: String,
+
+    /// Set by the server when the measurement was converted to its
+    /// canonical unit and originals are kept. Ignored on input.
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub original: Option<OriginalMeasurement>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OriginalMeasurement {
+    pub value: f64,
+    pub unit: String,
+}
+
+impl From<&SensorData> for SensorDataPayload {
+    fn from(data: &SensorData) -> Self {
+        Self {
+            device_id: data.device_id.clone(),
+            timestamp: data.timestamp,
+            temperature: data.temperature.as_ref().map(SensorMeasurement::from),
+            humidity: data.humidity.as_ref().map(SensorMeasurement::from),
+            co2: data.co2.as_ref().map(SensorMeasurement::from),
+            pressure: data.pressure.as_ref().map(SensorMeasurement::from),
+            pm1_0: data.pm1_0.as_ref().map(SensorMeasurement::from),
+            pm2_5: data.pm2_5.as_ref().map(SensorMeasurement::from),
+            pm10: data.pm10.as_ref().map(SensorMeasurement::from),
+            voc: data.voc.as_ref().map(SensorMeasurement::from),
+            illuminance: data.illuminance.as_ref().map(SensorMeasurement::from),
+            sound_level: data.sound_level.as_ref().map(SensorMeasurement::from),
+            additional_sensors: data
+                .additional_sensors
+                .iter()
+                .map(|(k, v)| (k.clone(), SensorMeasurement::from(v)))
+                .collect(),
+        }
+    }
+}
+
+impl From<SensorDataPayload> for SensorData {
+    fn from(payload: SensorDataPayload) -> Self {
+        Self {
+            device_id: payload.device_id,
+            timestamp: payload.timestamp,
+            temperature: payload.temperature.map(DomainMeasurement::from),
+            humidity: payload.humidity.map(DomainMeasurement::from),
+            co2: payload.co2.map(DomainMeasurement::from),
+            pressure: payload.pressure.map(DomainMeasurement::from),
+            pm1_0: payload.pm1_0.map(DomainMeasurement::from),
+            pm2_5: payload.pm2_5.map(DomainMeasurement::from),
+            pm10: payload.pm10.map(DomainMeasurement::from),
+            voc: payload.voc.map(DomainMeasurement::from),
+            illuminance: payload.illuminance.map(DomainMeasurement::from),
+            sound_level: payload.sound_level.map(DomainMeasurement::from),
+            additional_sensors: payload
+                .additional_sensors
+                .into_iter()
+                .map(|(k, v)| (k, DomainMeasurement::from(v)))
+                .collect(),
+        }
+    }
+}
+
+impl From<&DomainMeasurement> for SensorMeasurement {
+    fn from(m: &DomainMeasurement) -> Self {
+        Self {
+            value: m.value,
+            unit: m.unit.clone(),
+            original: m.original.as_ref().map(|o| OriginalMeasurement {
+                value: o.value,
+                unit: o.unit.clone(),
+            }),
+        }
+    }
+}
+
+impl From<SensorMeasurement> for DomainMeasurement {
+    fn from(m: SensorMeasurement) -> Self {
+        Self {
+            value: m.value,
+            unit: m.unit,
+            original: m.original.map(|o| DomainOriginal {
+                value: o.value,
+                unit: o.unit,
+            }),
+        }
+    }
+}
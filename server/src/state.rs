@@ -0,0 +1,70 @@
+//! Application State Module
+//!
+//! Provides the shared state injected into axum handlers.
+
+use std::sync::Arc;
+
+use domain::clock::{Clock, SystemClock};
+use domain::entities::SensorData;
+use domain::repositories::SensorRepository;
+use domain::sensors::normalization::OriginalUnits;
+use domain::sensors::policy::ValidationPolicy;
+use tokio::sync::broadcast;
+
+/// Number of readings buffered for live subscribers before the slowest
+/// ones start skipping messages.
+const SENSOR_EVENT_CAPACITY: usize = 1024;
+
+/// Shared state available to every handler.
+#[derive(Clone)]
+pub struct AppState {
+    pub repository: Arc<dyn SensorRepository>,
+    /// Whether ingestion keeps the received value and unit of converted
+    /// measurements.
+    pub original_units: OriginalUnits,
+    /// Value ranges incoming readings are validated against.
+    pub validation_policy: Arc<ValidationPolicy>,
+    /// Current time incoming timestamps are validated against.
+    pub clock: Arc<dyn Clock>,
+    sensor_events: broadcast::Sender<SensorData>,
+}
+
+impl AppState {
+    pub fn new(repository: Arc<dyn SensorRepository>) -> Self {
+        let (sensor_events, _) = broadcast::channel(SENSOR_EVENT_CAPACITY);
+        Self {
+            repository,
+            original_units: OriginalUnits::default(),
+            validation_policy: Arc::new(ValidationPolicy::default()),
+            clock: Arc::new(SystemClock),
+            sensor_events,
+        }
+    }
+
+    pub fn with_original_units(mut self, original_units: OriginalUnits) -> Self {
+        self.original_units = original_units;
+        self
+    }
+
+    pub fn with_validation_policy(mut self, validation_policy: ValidationPolicy) -> Self {
+        self.validation_policy = Arc::new(validation_policy);
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Publishes a saved reading to every live subscriber.
+    ///
+    /// Never blocks: when nobody is listening the reading is simply dropped.
+    pub fn publish(&self, data: &SensorData) {
+        let _ = self.sensor_events.send(data.clone());
+    }
+
+    /// Subscribes to readings published after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<SensorData> {
+        self.sensor_events.subscribe()
+    }
+}
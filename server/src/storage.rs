@@ -0,0 +1,55 @@
+//! Storage Module
+//!
+//! Builds the configured `SensorRepository` implementation.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use domain::repositories::SensorRepository;
+use infrastructure::persistence::{
+    CollectionLayout, InMemorySensorRepository, MongoSensorRepository, PostgresSensorRepository,
+    SqliteSensorRepository,
+};
+use mongodb::Client;
+
+use crate::config::StorageConfig;
+
+/// Name of the MongoDB collection holding sensor readings.
+const MONGO_COLLECTION: &str = "sensor_data";
+
+/// Connects to the configured backend and prepares its schema, collection or
+/// indexes.
+pub async fn connect(config: &StorageConfig) -> Result<Arc<dyn SensorRepository>> {
+    match config {
+        StorageConfig::Mongo {
+            uri,
+            database,
+            time_series,
+        } => {
+            let client = Client::with_uri_str(uri).await?;
+            let layout = if *time_series {
+                CollectionLayout::TimeSeries
+            } else {
+                CollectionLayout::Standard
+            };
+            let repository = MongoSensorRepository::initialize(
+                &client.database(database),
+                MONGO_COLLECTION,
+                layout,
+            )
+            .await?;
+            Ok(Arc::new(repository))
+        }
+        StorageConfig::Postgres { url } => {
+            let repository = PostgresSensorRepository::connect(url).await?;
+            repository.migrate().await?;
+            Ok(Arc::new(repository))
+        }
+        StorageConfig::Sqlite { path } => {
+            let repository = SqliteSensorRepository::open(path).await?;
+            repository.migrate().await?;
+            Ok(Arc::new(repository))
+        }
+        StorageConfig::Memory => Ok(Arc::new(InMemorySensorRepository::new())),
+    }
+}
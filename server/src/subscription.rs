@@ -0,0 +1,295 @@
+//! Subscription Module
+//!
+//! Provides the per-connection filter applied to the live sensor stream
+//! and the messages clients use to change it.
+
+use std::collections::{BTreeSet, HashSet};
+
+use domain::entities::SensorData;
+use serde::{Deserialize, Serialize};
+
+/// Measurement names that map onto the built-in `SensorData` fields.
+/// Any other name refers to a key of `additional_sensors`.
+const TEMPERATURE: &str = "temperature";
+const HUMIDITY: &str = "humidity";
+const CO2: &str = "co2";
+const PRESSURE: &str = "pressure";
+const PM1_0: &str = "pm1_0";
+const PM2_5: &str = "pm2_5";
+const PM10: &str = "pm10";
+const VOC: &str = "voc";
+const ILLUMINANCE: &str = "illuminance";
+const SOUND_LEVEL: &str = "sound_level";
+
+/// Messages a client may send to change its subscription.
+///
+/// Omitted or empty lists leave the corresponding part of the filter untouched.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SubscriptionRequest {
+    /// Restricts the stream to the given devices and/or measurements,
+    /// adding to any previous restriction.
+    Subscribe {
+        #[serde(default)]
+        device_ids: Vec<String>,
+        #[serde(default)]
+        measurements: Vec<String>,
+    },
+    /// Removes devices and/or measurements from the current restriction.
+    Unsubscribe {
+        #[serde(default)]
+        device_ids: Vec<String>,
+        #[serde(default)]
+        measurements: Vec<String>,
+    },
+    /// Drops every restriction and receives all readings again.
+    Reset,
+}
+
+/// Messages the server sends in reply to a subscription request, or to
+/// report the state of the stream.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SubscriptionResponse {
+    /// The filter now in effect. `None` means unrestricted.
+    Subscription {
+        device_ids: Option<BTreeSet<String>>,
+        measurements: Option<BTreeSet<String>>,
+    },
+    Error {
+        message: String,
+    },
+    /// The client fell behind and `skipped` readings were dropped, counted
+    /// before the filter is applied.
+    Lagged {
+        skipped: u64,
+    },
+}
+
+/// Filter deciding which readings, and which of their measurements,
+/// are forwarded to a client.
+///
+/// `None` means unrestricted; an empty set means nothing matches.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubscriptionFilter {
+    device_ids: Option<HashSet<String>>,
+    measurements: Option<HashSet<String>>,
+}
+
+impl SubscriptionFilter {
+    /// Applies a subscription request to the filter.
+    pub fn apply(&mut self, request: SubscriptionRequest) {
+        match request {
+            SubscriptionRequest::Subscribe {
+                device_ids,
+                measurements,
+            } => {
+                if !device_ids.is_empty() {
+                    self.device_ids
+                        .get_or_insert_with(HashSet::new)
+                        .extend(device_ids);
+                }
+                if !measurements.is_empty() {
+                    self.measurements
+                        .get_or_insert_with(HashSet::new)
+                        .extend(measurements);
+                }
+            }
+            SubscriptionRequest::Unsubscribe {
+                device_ids,
+                measurements,
+            } => {
+                if let Some(set) = self.device_ids.as_mut() {
+                    for id in &device_ids {
+                        set.remove(id);
+                    }
+                }
+                if let Some(set) = self.measurements.as_mut() {
+                    for name in &measurements {
+                        set.remove(name);
+                    }
+                }
+            }
+            SubscriptionRequest::Reset => *self = Self::default(),
+        }
+    }
+
+    /// Returns the part of the reading the client is subscribed to, or
+    /// `None` if nothing of it should be sent.
+    pub fn filter(&self, data: &SensorData) -> Option<SensorData> {
+        if let Some(ids) = &self.device_ids
+            && !ids.contains(&data.device_id)
+        {
+            return None;
+        }
+
+        let Some(names) = &self.measurements else {
+            return Some(data.clone());
+        };
+
+        let mut filtered = SensorData::new(data.device_id.clone(), data.timestamp);
+        if names.contains(TEMPERATURE) {
+            filtered.temperature = data.temperature.clone();
+        }
+        if names.contains(HUMIDITY) {
+            filtered.humidity = data.humidity.clone();
+        }
+        if names.contains(CO2) {
+            filtered.co2 = data.co2.clone();
+        }
+        if names.contains(PRESSURE) {
+            filtered.pressure = data.pressure.clone();
+        }
+        if names.contains(PM1_0) {
+            filtered.pm1_0 = data.pm1_0.clone();
+        }
+        if names.contains(PM2_5) {
+            filtered.pm2_5 = data.pm2_5.clone();
+        }
+        if names.contains(PM10) {
+            filtered.pm10 = data.pm10.clone();
+        }
+        if names.contains(VOC) {
+            filtered.voc = data.voc.clone();
+        }
+        if names.contains(ILLUMINANCE) {
+            filtered.illuminance = data.illuminance.clone();
+        }
+        if names.contains(SOUND_LEVEL) {
+            filtered.sound_level = data.sound_level.clone();
+        }
+        filtered.additional_sensors = data
+            .additional_sensors
+            .iter()
+            .filter(|(name, _)| names.contains(name.as_str()))
+            .map(|(name, m)| (name.clone(), m.clone()))
+            .collect();
+
+        let is_empty = filtered.temperature.is_none()
+            && filtered.humidity.is_none()
+            && filtered.co2.is_none()
+            && filtered.pressure.is_none()
+            && filtered.pm1_0.is_none()
+            && filtered.pm2_5.is_none()
+            && filtered.pm10.is_none()
+            && filtered.voc.is_none()
+            && filtered.illuminance.is_none()
+            && filtered.sound_level.is_none()
+            && filtered.additional_sensors.is_empty();
+        (!is_empty).then_some(filtered)
+    }
+
+    /// Describes the filter currently in effect.
+    pub fn to_response(&self) -> SubscriptionResponse {
+        SubscriptionResponse::Subscription {
+            device_ids: self
+                .device_ids
+                .as_ref()
+                .map(|s| s.iter().cloned().collect()),
+            measurements: self
+                .measurements
+                .as_ref()
+                .map(|s| s.iter().cloned().collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn reading(device_id: &str) -> SensorData {
+        SensorData::new(device_id.to_string(), Utc::now())
+            .with_temperature(22.0, "celsius")
+            .with_co2(420.0, "ppm")
+            .with_pressure(1013.25, "hPa")
+            .with_pm2_5(12.5, "µg/m³")
+            .with_sound_level(42.5, "dBA")
+            .with_additional_sensor("wind_speed", 3.5, "m/s")
+    }
+
+    fn subscribe(device_ids: &[&str], measurements: &[&str]) -> SubscriptionRequest {
+        SubscriptionRequest::Subscribe {
+            device_ids: device_ids.iter().map(|s| s.to_string()).collect(),
+            measurements: measurements.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn default_filter_passes_everything() {
+        let filter = SubscriptionFilter::default();
+
+        let result = filter.filter(&reading("device-001")).unwrap();
+
+        assert!(result.temperature.is_some());
+        assert!(result.co2.is_some());
+        assert!(result.pressure.is_some());
+        assert!(result.additional_sensors.contains_key("wind_speed"));
+    }
+
+    #[test]
+    fn restricts_to_subscribed_devices() {
+        let mut filter = SubscriptionFilter::default();
+        filter.apply(subscribe(&["device-001"], &[]));
+
+        assert!(filter.filter(&reading("device-001")).is_some());
+        assert!(filter.filter(&reading("device-002")).is_none());
+    }
+
+    #[test]
+    fn projects_subscribed_measurements() {
+        let mut filter = SubscriptionFilter::default();
+        filter.apply(subscribe(
+            &[],
+            &["pressure", "pm2_5", "sound_level", "wind_speed"],
+        ));
+
+        let result = filter.filter(&reading("device-001")).unwrap();
+
+        assert!(result.temperature.is_none());
+        assert!(result.co2.is_none());
+        assert!(result.pressure.is_some());
+        assert!(result.pm2_5.is_some());
+        assert!(result.sound_level.is_some());
+        assert!(result.additional_sensors.contains_key("wind_speed"));
+    }
+
+    #[test]
+    fn skips_readings_without_subscribed_measurements() {
+        let mut filter = SubscriptionFilter::default();
+        filter.apply(subscribe(&[], &["humidity"]));
+
+        assert!(filter.filter(&reading("device-001")).is_none());
+    }
+
+    #[test]
+    fn unsubscribe_removes_devices() {
+        let mut filter = SubscriptionFilter::default();
+        filter.apply(subscribe(&["device-001", "device-002"], &[]));
+        filter.apply(SubscriptionRequest::Unsubscribe {
+            device_ids: vec!["device-001".to_string()],
+            measurements: vec![],
+        });
+
+        assert!(filter.filter(&reading("device-001")).is_none());
+        assert!(filter.filter(&reading("device-002")).is_some());
+    }
+
+    #[test]
+    fn reset_removes_all_restrictions() {
+        let mut filter = SubscriptionFilter::default();
+        filter.apply(subscribe(&["device-001"], &["co2"]));
+        filter.apply(SubscriptionRequest::Reset);
+
+        assert_eq!(filter, SubscriptionFilter::default());
+    }
+
+    #[test]
+    fn parses_subscribe_message() {
+        let request: SubscriptionRequest =
+            serde_json::from_str(r#"{"type":"subscribe","device_ids":["device-001"]}"#).unwrap();
+
+        assert_eq!(request, subscribe(&["device-001"], &[]));
+    }
+}